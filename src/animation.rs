@@ -0,0 +1,192 @@
+// Faces for the birds standing in as targets: they blink now and then, look scared while a
+// launched pig flies close by, and keep a bruised face once something hits them hard. The
+// King and Normal pigs only have one frame each, so they stay as they are.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    destruction::{HEAVY_IMPULSE, Impact},
+    level::PigType,
+    slingshot::{Bird, OnSlingshot},
+};
+
+// Seconds between blinks, picked at random in this range, and how long a blink lasts
+const BLINK_INTERVAL: (f32, f32) = (2.0, 6.0);
+const BLINK_SECONDS: f32 = 0.15;
+// A launched pig closer than this (pixels), and still flying, scares a target
+const SCARED_DISTANCE: f32 = 250.0;
+const SCARED_SPEED: f32 = 100.0;
+// Hits lighter than this are just bodies settling
+const DAMAGE_IMPULSE: f32 = HEAVY_IMPULSE * 0.1;
+
+pub struct AnimationPlugin;
+
+impl Plugin for AnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<Impact>().add_systems(
+            Update,
+            (add_face_system, damage_face_system, face_system).chain(),
+        );
+    }
+}
+
+#[derive(Component)]
+pub struct Face {
+    idle: Handle<Image>,
+    blink: Handle<Image>,
+    scared: Handle<Image>,
+    damaged: Handle<Image>,
+    is_damaged: bool,
+    // Counts down to the next blink, and below zero while blinking
+    blink_in: f32,
+}
+
+// A target's frames in assets/birds: the image name, and which of its extra frames has the
+// eyes shut and which the open-mouthed yell. The extras aren't in the same order for every bird.
+struct FaceFrames {
+    name: &'static str,
+    blink: &'static str,
+    scared: &'static str,
+}
+
+const EYES_SHUT_FIRST: (&str, &str) = ("_extra_1", "_extra_2");
+const YELL_FIRST: (&str, &str) = ("_extra_2", "_extra_1");
+
+// For the types that have them
+fn face_frames(pig_type: PigType) -> Option<FaceFrames> {
+    let (name, (blink, scared)) = match pig_type {
+        PigType::King | PigType::Normal => return None,
+        PigType::RedBird => ("red", YELL_FIRST),
+        PigType::BombBird => ("black", EYES_SHUT_FIRST),
+        PigType::TriangleBird => ("yellow", EYES_SHUT_FIRST),
+        PigType::EggBird => ("white", YELL_FIRST),
+        PigType::BlueBird => ("blue", EYES_SHUT_FIRST),
+    };
+    Some(FaceFrames {
+        name,
+        blink,
+        scared,
+    })
+}
+
+fn next_blink() -> f32 {
+    rand::rng().random_range(BLINK_INTERVAL.0..=BLINK_INTERVAL.1)
+}
+
+fn add_face_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    pig_q: Query<(Entity, &PigType), Added<PigType>>,
+) {
+    for (entity, pig_type) in pig_q.iter() {
+        let Some(frames) = face_frames(*pig_type) else {
+            continue;
+        };
+        let frame = |suffix: &str| asset_server.load(format!("birds/{}{suffix}.png", frames.name));
+        commands.entity(entity).insert(Face {
+            idle: frame(""),
+            blink: frame(frames.blink),
+            scared: frame(frames.scared),
+            damaged: frame("_damaged"),
+            is_damaged: false,
+            blink_in: next_blink(),
+        });
+    }
+}
+
+fn damage_face_system(mut impacts: MessageReader<Impact>, mut face_q: Query<&mut Face>) {
+    for impact in impacts.read() {
+        if impact.impulse < DAMAGE_IMPULSE {
+            continue;
+        }
+        for entity in impact.entities {
+            if let Ok(mut face) = face_q.get_mut(entity) {
+                face.is_damaged = true;
+            }
+        }
+    }
+}
+
+// Scared beats bruised, which beats blinking; a bruised face doesn't blink
+fn face_system(
+    time: Res<Time>,
+    mut face_q: Query<(&mut Face, &mut Sprite, &Transform)>,
+    bird_q: Query<(&Transform, &LinearVelocity), (With<Bird>, Without<OnSlingshot>)>,
+) {
+    for (mut face, mut sprite, transform) in face_q.iter_mut() {
+        face.blink_in -= time.delta_secs();
+        if face.blink_in < -BLINK_SECONDS {
+            face.blink_in = next_blink();
+        }
+
+        let position = transform.translation.truncate();
+        let scared = bird_q.iter().any(|(bird, velocity)| {
+            velocity.length() > SCARED_SPEED
+                && bird.translation.truncate().distance(position) < SCARED_DISTANCE
+        });
+        let frame = if scared {
+            &face.scared
+        } else if face.is_damaged {
+            &face.damaged
+        } else if face.blink_in < 0.0 {
+            &face.blink
+        } else {
+            &face.idle
+        };
+        // Only touch the sprite on a change, so it isn't re-extracted every frame
+        if sprite.image != *frame {
+            sprite.image = frame.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PIG_TYPES: [PigType; 7] = [
+        PigType::King,
+        PigType::Normal,
+        PigType::RedBird,
+        PigType::BombBird,
+        PigType::TriangleBird,
+        PigType::EggBird,
+        PigType::BlueBird,
+    ];
+
+    #[test]
+    fn every_face_has_all_its_frames() {
+        for frames in PIG_TYPES.into_iter().filter_map(face_frames) {
+            for suffix in ["", frames.blink, frames.scared, "_damaged"] {
+                let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join(format!("assets/birds/{}{suffix}.png", frames.name));
+                assert!(path.is_file(), "{} doesn't exist", path.display());
+            }
+        }
+    }
+
+    #[test]
+    fn faces_blink_and_yell_with_different_frames() {
+        for frames in PIG_TYPES.into_iter().filter_map(face_frames) {
+            assert_ne!(
+                frames.blink, frames.scared,
+                "{} blinks by yelling",
+                frames.name
+            );
+            for suffix in [frames.blink, frames.scared] {
+                assert!(
+                    ["_extra_1", "_extra_2"].contains(&suffix),
+                    "{} has no {suffix} frame",
+                    frames.name
+                );
+            }
+        }
+        // The two orders both occur in the shipped images
+        let blink_of = |pig_type| face_frames(pig_type).unwrap().blink;
+        assert_eq!(blink_of(PigType::RedBird), "_extra_2");
+        assert_eq!(blink_of(PigType::EggBird), "_extra_2");
+        assert_eq!(blink_of(PigType::TriangleBird), "_extra_1");
+        assert_eq!(blink_of(PigType::BlueBird), "_extra_1");
+    }
+}
@@ -0,0 +1,279 @@
+// Camera controller: frames the slingshot while aiming, tracks the launched bird in flight
+// and pans back once the level has settled, never showing anything outside the level bounds.
+// The player can also look around with the mouse wheel, right-drag, or two-finger pan and pinch.
+use avian2d::prelude::*;
+use bevy::{
+    input::mouse::{AccumulatedMouseScroll, MouseScrollUnit},
+    prelude::*,
+};
+use bevy_egui::input::EguiWantsInput;
+
+use crate::{
+    level::{Block, CurrentLevel, Invisible, LEVELS, Pig},
+    sim::all_settled,
+    slingshot::{Bird, OnSlingshot, SLINGSHOT_POS},
+};
+
+// Minimum world area kept visible, whatever the window shape
+const VIEW_SIZE: f32 = 1200.0;
+// Space kept between the slingshot and the left edge of the view while aiming
+const AIM_MARGIN: f32 = 200.0;
+// How quickly the camera closes the gap to its target, per second
+const FOLLOW_SPEED: f32 = 4.0;
+const RETURN_SPEED: f32 = 2.0;
+// Player zoom limits, relative to the default view
+const MIN_ZOOM: f32 = 0.4;
+const MAX_ZOOM: f32 = 2.0;
+// Zoom change per mouse wheel line
+const WHEEL_ZOOM_STEP: f32 = 1.1;
+// Pixel-based scrolling (trackpads) counts this many pixels as one line
+const PIXELS_PER_LINE: f32 = 100.0;
+// Space kept around the blocks and pigs when fitting the level
+const FIT_MARGIN: f32 = 100.0;
+const FIT_LEVEL_KEY: KeyCode = KeyCode::KeyF;
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraTarget>()
+            .init_resource::<CameraZoom>()
+            .add_systems(Startup, spawn_camera)
+            .add_systems(
+                Update,
+                (camera_target_system, camera_input_system, camera_system).chain(),
+            );
+    }
+}
+
+fn spawn_camera(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
+
+#[derive(Resource, Default)]
+pub enum CameraTarget {
+    #[default]
+    Slingshot,
+    Bird(Entity),
+    // Somewhere the player panned to; stays put until the next launch
+    Point(Vec2),
+}
+
+// Player zoom on top of the default view, > 1.0 shows more of the level
+#[derive(Resource)]
+pub struct CameraZoom(pub f32);
+
+impl Default for CameraZoom {
+    fn default() -> Self {
+        CameraZoom(1.0)
+    }
+}
+
+// Follows a bird as soon as it leaves the slingshot and lets go once it's gone or
+// everything has stopped moving
+pub fn camera_target_system(
+    mut target: ResMut<CameraTarget>,
+    mut launched: RemovedComponents<OnSlingshot>,
+    bird_q: Query<(), With<Bird>>,
+    body_q: Query<(&RigidBody, &LinearVelocity)>,
+) {
+    for entity in launched.read() {
+        if bird_q.contains(entity) {
+            *target = CameraTarget::Bird(entity);
+        }
+    }
+
+    if let CameraTarget::Bird(bird) = *target {
+        if all_settled(body_q.iter()) || !bird_q.contains(bird) {
+            *target = CameraTarget::Slingshot;
+        }
+    }
+}
+
+// Mouse wheel zooms toward the cursor, right-drag pans, two fingers pan and pinch-zoom,
+// and FIT_LEVEL_KEY zooms out to show every block and pig
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn camera_input_system(
+    mut target: ResMut<CameraTarget>,
+    mut zoom: ResMut<CameraZoom>,
+    mut last_cursor: Local<Option<Vec2>>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    scroll: Res<AccumulatedMouseScroll>,
+    touches: Res<Touches>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    egui_input: Res<EguiWantsInput>,
+    windows: Query<&Window>,
+    level_q: Query<
+        &Transform,
+        (
+            Or<(With<Block>, With<Pig>)>,
+            Without<Invisible>,
+            Without<Camera2d>,
+        ),
+    >,
+    mut camera_q: Query<(&Projection, &mut Transform), With<Camera2d>>,
+) {
+    let Some(window) = windows.iter().next() else {
+        return;
+    };
+    let Some((Projection::Orthographic(ortho), mut transform)) = camera_q.iter_mut().next() else {
+        return;
+    };
+    let mut view = View {
+        center: transform.translation.truncate(),
+        scale: ortho.scale,
+        window_size: window.size(),
+    };
+    let mut moved = false;
+
+    // Mouse
+    let cursor = window.cursor_position();
+    if !egui_input.wants_any_pointer_input() {
+        let lines = match scroll.unit {
+            MouseScrollUnit::Line => scroll.delta.y,
+            MouseScrollUnit::Pixel => scroll.delta.y / PIXELS_PER_LINE,
+        };
+        if let Some(cursor) = cursor
+            && lines != 0.0
+        {
+            view.zoom_about(cursor, &mut zoom, WHEEL_ZOOM_STEP.powf(-lines));
+            moved = true;
+        }
+        if mouse_button.pressed(MouseButton::Right)
+            && let (Some(cursor), Some(last)) = (cursor, *last_cursor)
+        {
+            view.pan(cursor - last);
+            moved = true;
+        }
+    }
+    *last_cursor = cursor;
+
+    // Two-finger touch: the midpoint pans, the change in spacing zooms
+    let fingers: Vec<&Touch> = touches.iter().collect();
+    if let [a, b] = fingers.as_slice() {
+        let midpoint = (a.position() + b.position()) / 2.0;
+        let previous_midpoint = (a.previous_position() + b.previous_position()) / 2.0;
+        let spacing = a.position().distance(b.position());
+        let previous_spacing = a.previous_position().distance(b.previous_position());
+        view.pan(midpoint - previous_midpoint);
+        if spacing > 0.0 && previous_spacing > 0.0 {
+            view.zoom_about(midpoint, &mut zoom, previous_spacing / spacing);
+        }
+        moved = true;
+    }
+
+    if keyboard.just_pressed(FIT_LEVEL_KEY) {
+        let (min, max) = level_q.iter().map(|t| t.translation.truncate()).fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), pos| (min.min(pos), max.max(pos)),
+        );
+        if min.x <= max.x {
+            let size = max - min + FIT_MARGIN * 2.0;
+            let needed_scale = (size / view.window_size).max_element();
+            zoom.0 = (needed_scale / default_scale(view.window_size)).clamp(MIN_ZOOM, MAX_ZOOM);
+            *target = CameraTarget::Point((min + max) / 2.0);
+        }
+        return;
+    }
+
+    if moved {
+        // Move straight away so dragging feels attached, and stay here until the next launch
+        transform.translation = view.center.extend(transform.translation.z);
+        *target = CameraTarget::Point(view.center);
+    }
+}
+
+// The camera's visible area, for turning screen movement into world movement
+struct View {
+    center: Vec2,
+    scale: f32,
+    window_size: Vec2,
+}
+
+impl View {
+    // World offset from the view centre of a window position (window y points down)
+    fn offset(&self, screen: Vec2) -> Vec2 {
+        let from_center = screen - self.window_size / 2.0;
+        Vec2::new(from_center.x, -from_center.y) * self.scale
+    }
+
+    // Moves the view so the world follows a drag of `screen_delta` window pixels
+    fn pan(&mut self, screen_delta: Vec2) {
+        self.center -= Vec2::new(screen_delta.x, -screen_delta.y) * self.scale;
+    }
+
+    // Scales the zoom by `factor`, keeping the world point under `screen` in place
+    fn zoom_about(&mut self, screen: Vec2, zoom: &mut CameraZoom, factor: f32) {
+        let new_zoom = (zoom.0 * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        let ratio = new_zoom / zoom.0;
+        self.center += self.offset(screen) * (1.0 - ratio);
+        self.scale *= ratio;
+        zoom.0 = new_zoom;
+    }
+}
+
+// Shows at least VIEW_SIZE x VIEW_SIZE, scale > 1.0 zooms out
+fn default_scale(window_size: Vec2) -> f32 {
+    (VIEW_SIZE / window_size).max_element()
+}
+
+pub fn camera_system(
+    time: Res<Time>,
+    target: Res<CameraTarget>,
+    zoom: Res<CameraZoom>,
+    current_level: Res<CurrentLevel>,
+    windows: Query<&Window>,
+    bird_q: Query<&Transform, (With<Bird>, Without<Camera2d>)>,
+    mut camera_q: Query<(&mut Projection, &mut Transform), With<Camera2d>>,
+) {
+    let Some(window) = windows.iter().next() else {
+        return;
+    };
+    let bounds = LEVELS[current_level.0].bounds;
+
+    for (mut projection, mut transform) in camera_q.iter_mut() {
+        let Projection::Orthographic(ortho) = &mut *projection else {
+            continue;
+        };
+
+        ortho.scale = default_scale(window.size()) * zoom.0;
+        let half_view = window.size() * ortho.scale / 2.0;
+
+        let (goal, speed) = match *target {
+            CameraTarget::Bird(bird) => match bird_q.get(bird) {
+                Ok(bird) => (bird.translation.truncate(), FOLLOW_SPEED),
+                Err(_) => continue,
+            },
+            CameraTarget::Point(point) => (point, RETURN_SPEED),
+            // Slingshot near the left edge, ground at the bottom
+            CameraTarget::Slingshot => (
+                Vec2::new(
+                    SLINGSHOT_POS.x - AIM_MARGIN + half_view.x,
+                    bounds.min.y + half_view.y,
+                ),
+                RETURN_SPEED,
+            ),
+        };
+        let goal = clamp_view(goal, half_view, bounds);
+
+        let current = transform.translation.truncate();
+        let next = current.lerp(goal, 1.0 - (-speed * time.delta_secs()).exp());
+        transform.translation = next.extend(transform.translation.z);
+    }
+}
+
+// Keeps the view inside the bounds, centring it on any axis where the level is smaller
+// than the view
+fn clamp_view(center: Vec2, half_view: Vec2, bounds: Rect) -> Vec2 {
+    let clamp_axis = |center: f32, half: f32, min: f32, max: f32| {
+        if max - min <= half * 2.0 {
+            (min + max) / 2.0
+        } else {
+            center.clamp(min + half, max - half)
+        }
+    };
+    Vec2::new(
+        clamp_axis(center.x, half_view.x, bounds.min.x, bounds.max.x),
+        clamp_axis(center.y, half_view.y, bounds.min.y, bounds.max.y),
+    )
+}
@@ -0,0 +1,306 @@
+// Command line interface. `play` opens the game window, every other command runs
+// headlessly so level checks can be scripted in a pipeline.
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use serde::Serialize;
+
+use angry_birds::{
+    level::LEVELS,
+    sim::{self, MAX_SHOT_SECONDS, Simulation},
+    slingshot::Shot,
+    solver, thumbnail,
+};
+
+pub(crate) const USAGE: &str = "\
+Usage: angry-birds [COMMAND]
+
+Commands:
+  play [level]                             Play the game (the default)
+  validate <level>                         Check the level stands with no input
+  simulate <level> --shots <file>          Fire the shots in <file>, print results as JSON
+  solve <level> [--max-birds <n>]          Search for shots that defeat every pig
+  render-thumbnail <level> [--out <file>]  Draw the level layout to a PNG
+
+<level> is a level id (e.g. internet) or its number, starting from 1.
+The --shots file is a JSON list such as [{\"angle_degrees\": 30.0, \"pull\": 120.0}].";
+
+const DEFAULT_MAX_BIRDS: usize = 5;
+
+pub(crate) enum Command {
+    Play { level: usize },
+    Validate { level: usize },
+    Simulate { level: usize, shots: PathBuf },
+    Solve { level: usize, max_birds: usize },
+    RenderThumbnail { level: usize, out: PathBuf },
+}
+
+pub(crate) fn parse(args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut args = Args::parse(args)?;
+    if args.positional.is_empty() {
+        return Ok(Command::Play { level: 0 });
+    }
+    let name = args.positional.remove(0);
+
+    let command = match name.as_str() {
+        "play" => Command::Play {
+            level: if args.positional.is_empty() {
+                0
+            } else {
+                args.level()?
+            },
+        },
+        "validate" => Command::Validate {
+            level: args.level()?,
+        },
+        "simulate" => Command::Simulate {
+            level: args.level()?,
+            shots: args
+                .option("--shots")
+                .ok_or("simulate needs --shots <file>")?
+                .into(),
+        },
+        "solve" => Command::Solve {
+            level: args.level()?,
+            max_birds: match args.option("--max-birds") {
+                Some(n) => n
+                    .parse()
+                    .map_err(|_| format!("--max-birds expects a number, got '{n}'"))?,
+                None => DEFAULT_MAX_BIRDS,
+            },
+        },
+        "render-thumbnail" => {
+            let level = args.level()?;
+            Command::RenderThumbnail {
+                level,
+                out: args
+                    .option("--out")
+                    .unwrap_or_else(|| format!("{}.png", LEVELS[level].id))
+                    .into(),
+            }
+        }
+        _ => return Err(format!("unknown command '{name}'")),
+    };
+
+    args.finish()?;
+    Ok(command)
+}
+
+// `play` is handed the level to open the game window at; main owns the windowed app
+pub(crate) fn run(command: Command, play: impl FnOnce(usize)) -> ExitCode {
+    match execute(command, play) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(command: Command, play: impl FnOnce(usize)) -> Result<ExitCode, String> {
+    match command {
+        Command::Play { level } => {
+            play(level);
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Validate { level } => {
+            let level = &LEVELS[level];
+            let problems = sim::check_stability(level);
+            if problems.is_empty() {
+                println!("{}: ok", level.id);
+                return Ok(ExitCode::SUCCESS);
+            }
+            println!("{}: unstable", level.id);
+            for problem in problems {
+                println!("  {problem}");
+            }
+            Ok(ExitCode::FAILURE)
+        }
+        Command::Simulate { level, shots } => {
+            let file = fs::read_to_string(&shots)
+                .map_err(|e| format!("couldn't read {}: {e}", shots.display()))?;
+            let shots: Vec<Shot> = serde_json::from_str(&file)
+                .map_err(|e| format!("couldn't parse {}: {e}", shots.display()))?;
+            print_json(&simulate(level, shots))
+        }
+        Command::Solve { level, max_birds } => {
+            let report = solver::solve(&LEVELS[level], max_birds);
+            let solved = report.solved();
+            print_json(&SolveOutput {
+                level: LEVELS[level].id,
+                solved,
+                birds_used: report.shots.len(),
+                report,
+            })?;
+            Ok(if solved {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            })
+        }
+        Command::RenderThumbnail { level, out } => {
+            thumbnail::render(&LEVELS[level])
+                .save(&out)
+                .map_err(|e| format!("couldn't write {}: {e}", out.display()))?;
+            println!("{}", out.display());
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SimulateOutput {
+    level: &'static str,
+    pigs_total: usize,
+    pigs_remaining: usize,
+    shots: Vec<ShotResult>,
+}
+
+#[derive(Serialize)]
+struct ShotResult {
+    #[serde(flatten)]
+    shot: Shot,
+    // Pigs still standing once this shot has settled
+    pigs_remaining: usize,
+}
+
+#[derive(Serialize)]
+struct SolveOutput {
+    level: &'static str,
+    solved: bool,
+    birds_used: usize,
+    #[serde(flatten)]
+    report: solver::SolverReport,
+}
+
+fn simulate(level: usize, shots: Vec<Shot>) -> SimulateOutput {
+    let mut sim = Simulation::new(&LEVELS[level]);
+    let pigs_total = sim.pig_count();
+    let shots = shots
+        .into_iter()
+        .map(|shot| {
+            sim.shoot(shot);
+            sim.run_until_settled(MAX_SHOT_SECONDS);
+            ShotResult {
+                shot,
+                pigs_remaining: sim.pig_count(),
+            }
+        })
+        .collect();
+
+    SimulateOutput {
+        level: LEVELS[level].id,
+        pigs_total,
+        pigs_remaining: sim.pig_count(),
+        shots,
+    }
+}
+
+fn print_json(value: &impl Serialize) -> Result<ExitCode, String> {
+    let json = serde_json::to_string_pretty(value).map_err(|e| e.to_string())?;
+    println!("{json}");
+    Ok(ExitCode::SUCCESS)
+}
+
+// Accepts a level id or its 1-based number
+fn find_level(arg: &str) -> Result<usize, String> {
+    if let Some(index) = LEVELS.iter().position(|level| level.id == arg) {
+        return Ok(index);
+    }
+    match arg.parse::<usize>() {
+        Ok(number) if (1..=LEVELS.len()).contains(&number) => Ok(number - 1),
+        _ => Err(format!(
+            "unknown level '{arg}', expected one of: {}",
+            LEVELS
+                .iter()
+                .map(|level| level.id)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )),
+    }
+}
+
+struct Args {
+    positional: Vec<String>,
+    options: Vec<(String, String)>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+        while let Some(arg) = args.next() {
+            if arg.starts_with("--") {
+                let value = args.next().ok_or_else(|| format!("{arg} needs a value"))?;
+                options.push((arg, value));
+            } else {
+                positional.push(arg);
+            }
+        }
+        Ok(Args {
+            positional,
+            options,
+        })
+    }
+
+    // Takes the single remaining positional argument as the level
+    fn level(&mut self) -> Result<usize, String> {
+        match self.positional.as_slice() {
+            [] => Err("missing <level>".into()),
+            [level] => {
+                let level = find_level(level)?;
+                self.positional.clear();
+                Ok(level)
+            }
+            [_, extra, ..] => Err(format!("unexpected argument '{extra}'")),
+        }
+    }
+
+    fn option(&mut self, name: &str) -> Option<String> {
+        let index = self.options.iter().position(|(option, _)| option == name)?;
+        Some(self.options.remove(index).1)
+    }
+
+    fn finish(self) -> Result<(), String> {
+        match self.options.first() {
+            Some((option, _)) => Err(format!("unknown option '{option}'")),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_args(args: &[&str]) -> Result<Command, String> {
+        parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn no_arguments_plays_the_first_level() {
+        assert!(matches!(parse_args(&[]), Ok(Command::Play { level: 0 })));
+    }
+
+    #[test]
+    fn levels_are_found_by_id_or_number() {
+        assert!(matches!(
+            parse_args(&["validate", LEVELS[0].id]),
+            Ok(Command::Validate { level: 0 })
+        ));
+        assert!(matches!(
+            parse_args(&["solve", "1", "--max-birds", "3"]),
+            Ok(Command::Solve {
+                level: 0,
+                max_birds: 3
+            })
+        ));
+        assert!(parse_args(&["validate", "0"]).is_err());
+    }
+
+    #[test]
+    fn bad_arguments_are_rejected() {
+        assert!(parse_args(&["simulate", "1"]).is_err());
+        assert!(parse_args(&["validate", "1", "--shots", "a.json"]).is_err());
+        assert!(parse_args(&["fly"]).is_err());
+    }
+}
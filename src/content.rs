@@ -0,0 +1,162 @@
+// The educational content: what each described block is and the disasters pigs carry,
+// loaded from `assets/content/*.content.json` so it can be extended or corrected without
+// touching code. Levels refer to entries by id; each language has its own pack, see
+// `locale`.
+use std::marker::PhantomData;
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use serde::{Deserialize, de::DeserializeOwned};
+
+pub struct ContentPlugin;
+
+impl Plugin for ContentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ContentPack>()
+            .add_message::<ContentDiscovered>()
+            .register_asset_loader(JsonLoader::<ContentPack>::new(&["content.json"]));
+    }
+}
+
+// Sent when the player comes across an entry: hovering or destroying a described block,
+// or launching a pig
+#[derive(Message)]
+pub struct ContentDiscovered(pub &'static str);
+
+#[derive(Deserialize, Clone)]
+pub struct ContentEntry {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub year: u32,
+    #[serde(default)]
+    pub link: Option<String>,
+    // Asked after launching a pig with this disaster, see `quiz`
+    #[serde(default)]
+    pub quiz: Option<Quiz>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Quiz {
+    pub question: String,
+    pub answers: Vec<String>,
+    // Index into `answers`
+    pub correct: usize,
+}
+
+#[derive(Asset, TypePath, Deserialize)]
+pub struct ContentPack {
+    pub entries: Vec<ContentEntry>,
+}
+
+impl ContentPack {
+    pub fn from_json(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(bytes)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ContentEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+}
+
+#[derive(Debug)]
+pub enum JsonAssetError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for JsonAssetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonAssetError::Io(err) => write!(f, "couldn't read asset: {err}"),
+            JsonAssetError::Json(err) => write!(f, "invalid JSON asset: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for JsonAssetError {}
+
+// Loads any asset that deserializes straight from a JSON file
+pub struct JsonLoader<A> {
+    extensions: &'static [&'static str],
+    asset: PhantomData<fn() -> A>,
+}
+
+impl<A> JsonLoader<A> {
+    pub fn new(extensions: &'static [&'static str]) -> Self {
+        JsonLoader {
+            extensions,
+            asset: PhantomData,
+        }
+    }
+}
+
+impl<A: Asset + DeserializeOwned> AssetLoader for JsonLoader<A> {
+    type Asset = A;
+    type Settings = ();
+    type Error = JsonAssetError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<A, JsonAssetError> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(JsonAssetError::Io)?;
+        serde_json::from_slice(&bytes).map_err(JsonAssetError::Json)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.extensions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::LEVELS;
+
+    #[test]
+    fn levels_only_refer_to_shipped_content() {
+        let pack =
+            ContentPack::from_json(include_bytes!("../assets/content/open_source.content.json"))
+                .expect("shipped content pack should parse");
+
+        for level in LEVELS {
+            let (blocks, _) = (level.layout)();
+            let ids = blocks
+                .iter()
+                .filter_map(|block| block.description)
+                .chain(level.disasters.iter().map(|disaster| disaster.content));
+            for id in ids {
+                assert!(
+                    pack.get(id).is_some(),
+                    "level {} refers to missing content {id}",
+                    level.id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn quiz_answers_are_in_range() {
+        let pack =
+            ContentPack::from_json(include_bytes!("../assets/content/open_source.content.json"))
+                .unwrap();
+        for entry in &pack.entries {
+            if let Some(quiz) = &entry.quiz {
+                assert!(
+                    quiz.correct < quiz.answers.len(),
+                    "bad quiz for {}",
+                    entry.id
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+// Debug tooling: an overlay toggled with OVERLAY_KEY, plus the physics debug renderer and
+// entity inspector when built with the `physics-debug` / `inspector` features.
+use avian2d::prelude::*;
+use bevy::{
+    diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin},
+    prelude::*,
+};
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use crate::{
+    destruction::{PIG_DEFEAT_SPEED, STEEL_BREAK_SPEED, WOOD_BREAK_SPEED, Weakened, break_speed},
+    level::{Block, BlockMaterial, Invisible, Pig},
+    locale::Localizer,
+    slingshot::{Bird, OnSlingshot},
+};
+
+const OVERLAY_KEY: KeyCode = KeyCode::F3;
+// Velocity arrows are drawn this many seconds of travel long
+const VELOCITY_ARROW_SECONDS: f32 = 0.1;
+const OUTLINE_COLOR: Color = Color::srgb(0.2, 1.0, 1.0);
+const CONTACT_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+const VELOCITY_COLOR: Color = Color::srgb(1.0, 1.0, 0.2);
+
+#[derive(Resource, Default)]
+pub struct DebugOverlay(pub bool);
+
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "physics-debug")]
+        app.add_plugins(PhysicsDebugPlugin::default());
+        #[cfg(feature = "inspector")]
+        app.add_plugins(bevy_inspector_egui::quick::WorldInspectorPlugin::new());
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin::default());
+        }
+        app.init_resource::<DebugOverlay>()
+            .add_systems(
+                Update,
+                (
+                    toggle_overlay_system,
+                    overlay_gizmos_system.run_if(overlay_enabled),
+                ),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                overlay_ui_system.run_if(overlay_enabled),
+            );
+    }
+}
+
+fn overlay_enabled(overlay: Res<DebugOverlay>) -> bool {
+    overlay.0
+}
+
+fn toggle_overlay_system(keyboard: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<DebugOverlay>) {
+    if keyboard.just_pressed(OVERLAY_KEY) {
+        overlay.0 = !overlay.0;
+    }
+}
+
+// Collider outlines, contact points and velocity arrows for birds and pigs
+fn overlay_gizmos_system(
+    mut gizmos: Gizmos,
+    collisions: Collisions,
+    collider_q: Query<(&Collider, &ColliderAabb, &GlobalTransform), Without<Invisible>>,
+    moving_q: Query<(&GlobalTransform, &LinearVelocity), Or<(With<Bird>, With<Pig>)>>,
+) {
+    for (collider, aabb, transform) in collider_q.iter() {
+        let (_, rotation, translation) = transform.to_scale_rotation_translation();
+        let isometry = Isometry2d::new(
+            translation.truncate(),
+            Rot2::radians(rotation.to_euler(EulerRot::XYZ).2),
+        );
+        if let Some(cuboid) = collider.shape().as_cuboid() {
+            let size = Vec2::new(cuboid.half_extents.x, cuboid.half_extents.y) * 2.0;
+            gizmos.rect_2d(isometry, size, OUTLINE_COLOR);
+        } else if let Some(ball) = collider.shape().as_ball() {
+            gizmos.circle_2d(isometry, ball.radius, OUTLINE_COLOR);
+        } else {
+            // Triangles and capsules: their bounding box is close enough to debug with
+            gizmos.rect_2d(aabb.center(), aabb.size(), OUTLINE_COLOR);
+        }
+    }
+
+    for contacts in collisions.iter() {
+        for manifold in contacts.manifolds.iter() {
+            for point in manifold.points.iter() {
+                gizmos.circle_2d(point.point, 3.0, CONTACT_COLOR);
+            }
+        }
+    }
+
+    for (transform, velocity) in moving_q.iter() {
+        let start = transform.translation().truncate();
+        gizmos.arrow_2d(
+            start,
+            start + velocity.0 * VELOCITY_ARROW_SECONDS,
+            VELOCITY_COLOR,
+        );
+    }
+}
+
+// Stats panel, plus each block's and pig's break speed and each flying body's current
+// speed drawn next to it
+fn overlay_ui_system(
+    mut contexts: EguiContexts,
+    diagnostics: Res<DiagnosticsStore>,
+    entity_q: Query<()>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    block_q: Query<
+        (&GlobalTransform, &BlockMaterial, Has<Weakened>),
+        (With<Block>, Without<Invisible>),
+    >,
+    pig_q: Query<(&GlobalTransform, &LinearVelocity), With<Pig>>,
+    bird_q: Query<&LinearVelocity, (With<Bird>, Without<OnSlingshot>)>,
+    locale: Localizer,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.smoothed());
+    let fastest_bird = bird_q.iter().map(|v| v.length()).fold(0.0, f32::max);
+    let fastest_pig = pig_q.iter().map(|(_, v)| v.length()).fold(0.0, f32::max);
+
+    egui::Window::new(locale.tr("debug.title"))
+        .id(egui::Id::new("debug"))
+        .anchor(egui::Align2::RIGHT_BOTTOM, (-10.0, -10.0))
+        .show(ctx, |ui| {
+            let fps = fps.map_or("-".to_string(), |fps| format!("{fps:.0}"));
+            ui.label(locale.tr_args("debug.fps", &[("fps", &fps)]));
+            ui.label(locale.tr_args("debug.entities", &[("count", &entity_q.iter().count())]));
+            ui.label(locale.tr_args(
+                "debug.counts",
+                &[
+                    ("blocks", &block_q.iter().count()),
+                    ("pigs", &pig_q.iter().count()),
+                ],
+            ));
+            ui.separator();
+            ui.label(locale.tr_args(
+                "debug.fastest-bird",
+                &[
+                    ("speed", &format!("{fastest_bird:.0}")),
+                    ("wood", &format!("{WOOD_BREAK_SPEED:.0}")),
+                    ("steel", &format!("{STEEL_BREAK_SPEED:.0}")),
+                ],
+            ));
+            ui.label(locale.tr_args(
+                "debug.fastest-pig",
+                &[
+                    ("speed", &format!("{fastest_pig:.0}")),
+                    ("defeat", &format!("{PIG_DEFEAT_SPEED:.0}")),
+                ],
+            ));
+            ui.label(locale.tr_args("debug.toggle", &[("key", &format!("{OVERLAY_KEY:?}"))]));
+        });
+
+    let Some((camera, camera_transform)) = camera_q.iter().next() else {
+        return;
+    };
+    let painter = ctx.debug_painter();
+    let mut label = |pos: &GlobalTransform, text: String| {
+        if let Ok(screen) = camera.world_to_viewport(camera_transform, pos.translation()) {
+            painter.text(
+                egui::pos2(screen.x, screen.y),
+                egui::Align2::CENTER_CENTER,
+                text,
+                egui::FontId::monospace(11.0),
+                egui::Color32::WHITE,
+            );
+        }
+    };
+    // The speed a bird has to beat to break this block, which a Weakened block halves
+    for (transform, material, weakened) in block_q.iter() {
+        let key = if weakened {
+            "debug.block-weakened"
+        } else {
+            "debug.block"
+        };
+        let speed = format!("{:.0}", break_speed(*material, weakened));
+        label(transform, locale.tr_args(key, &[("speed", &speed)]));
+    }
+    for (transform, velocity) in pig_q.iter() {
+        label(
+            transform,
+            format!("{:.0}/{PIG_DEFEAT_SPEED:.0}", velocity.length()),
+        );
+    }
+}
@@ -0,0 +1,197 @@
+// Dependencies between described blocks: levels declare which projects are built on which,
+// and when a project is destroyed everything depending on it is weakened. Anything that was
+// already weakened collapses, which can bring down whatever depends on it in turn.
+use bevy::prelude::*;
+
+use crate::{
+    content::ContentDiscovered,
+    destruction::{
+        BlockBreaker, BlockBroken, ProjectDestroyed, Weakened, block_destruction_system, weaken,
+    },
+    disaster::disaster_impact_system,
+    level::{BlockDescription, CurrentLevel, LEVELS, Level},
+    menu::LoadLevel,
+};
+
+const DEPENDS_ON_COLOR: Color = Color::srgb(1.0, 0.4, 0.3);
+const NEEDED_BY_COLOR: Color = Color::srgb(0.3, 0.8, 1.0);
+
+pub struct DependencyPlugin;
+
+impl Plugin for DependencyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ProjectDestroyed>()
+            .add_message::<ContentDiscovered>()
+            .add_message::<BlockBroken>()
+            .add_message::<LoadLevel>()
+            .init_resource::<HoveredProject>()
+            .add_systems(
+                Update,
+                (
+                    // Sees what they destroy in the same frame
+                    cascade_failure_system
+                        .after(block_destruction_system)
+                        .after(disaster_impact_system),
+                    // The headless simulation has nothing to draw with
+                    dependency_links_system.run_if(resource_exists::<GizmoConfigStore>),
+                ),
+            );
+    }
+}
+
+// `project` stops working without `depends_on`; both are content ids of described blocks
+#[derive(Clone, Copy)]
+pub struct Dependency {
+    pub project: &'static str,
+    pub depends_on: &'static str,
+}
+
+// Projects `id` is built on
+pub fn depends_on(level: &Level, id: &str) -> impl Iterator<Item = &'static str> {
+    level
+        .dependencies
+        .iter()
+        .filter(move |dependency| dependency.project == id)
+        .map(|dependency| dependency.depends_on)
+}
+
+// Projects built on `id`
+pub fn needed_by(level: &Level, id: &str) -> impl Iterator<Item = &'static str> {
+    level
+        .dependencies
+        .iter()
+        .filter(move |dependency| dependency.depends_on == id)
+        .map(|dependency| dependency.project)
+}
+
+// Content id of the described block under the pointer, set by `ui::hover_info_system`
+#[derive(Resource, Default)]
+pub struct HoveredProject(pub Option<&'static str>);
+
+// Each failed project is handled once per try at the level. Collapses report the collapsed
+// project as destroyed too, and it comes back around here already handled.
+fn cascade_failure_system(
+    mut commands: Commands,
+    mut breaker: BlockBreaker,
+    mut destroyed: MessageReader<ProjectDestroyed>,
+    mut load_level: MessageReader<LoadLevel>,
+    current_level: Res<CurrentLevel>,
+    mut block_q: Query<(Entity, &BlockDescription, Has<Weakened>, &mut Sprite)>,
+    mut handled: Local<Vec<&'static str>>,
+) {
+    if load_level.read().count() > 0 {
+        handled.clear();
+    }
+    let level = &LEVELS[current_level.0];
+    let mut failed: Vec<&'static str> = destroyed.read().map(|destroyed| destroyed.0).collect();
+    let mut weakened_now = Vec::new();
+    let mut collapsed = Vec::new();
+
+    while let Some(id) = failed.pop() {
+        if handled.contains(&id) {
+            continue;
+        }
+        handled.push(id);
+
+        for dependent in needed_by(level, id) {
+            for (entity, description, weakened, mut sprite) in block_q.iter_mut() {
+                if description.0 != dependent || collapsed.contains(&entity) {
+                    continue;
+                }
+                if weakened || weakened_now.contains(&entity) {
+                    breaker.break_block(entity);
+                    collapsed.push(entity);
+                    failed.push(dependent);
+                } else {
+                    weaken(&mut commands, entity, &mut sprite);
+                    weakened_now.push(entity);
+                }
+            }
+        }
+    }
+    breaker.finish();
+}
+
+// Arrows from the hovered project to what it depends on, and from what depends on it
+fn dependency_links_system(
+    mut gizmos: Gizmos,
+    hovered: Res<HoveredProject>,
+    current_level: Res<CurrentLevel>,
+    block_q: Query<(&BlockDescription, &Transform)>,
+) {
+    let Some(id) = hovered.0 else {
+        return;
+    };
+    let level = &LEVELS[current_level.0];
+    let position_of = |id: &str| {
+        block_q
+            .iter()
+            .find(|(description, _)| description.0 == id)
+            .map(|(_, transform)| transform.translation.truncate())
+    };
+    let Some(hovered_pos) = position_of(id) else {
+        return;
+    };
+
+    for foundation in depends_on(level, id).filter_map(position_of) {
+        gizmos.arrow_2d(hovered_pos, foundation, DEPENDS_ON_COLOR);
+    }
+    for dependent in needed_by(level, id).filter_map(position_of) {
+        gizmos.arrow_2d(dependent, hovered_pos, NEEDED_BY_COLOR);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_destroyed_project_only_weakens_its_dependents() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, DependencyPlugin))
+            .insert_resource(CurrentLevel(0));
+        let dependency = LEVELS[0].dependencies[0];
+        let dependent = app
+            .world_mut()
+            .spawn((
+                BlockDescription(dependency.project),
+                crate::level::BlockMaterial::Steel,
+                Transform::default(),
+                Sprite::default(),
+                crate::level::Block,
+            ))
+            .id();
+
+        // The same failure reported twice, in different frames
+        for _ in 0..2 {
+            app.world_mut()
+                .write_message(ProjectDestroyed(dependency.depends_on));
+            app.update();
+        }
+        app.update();
+
+        let dependent = app
+            .world()
+            .get_entity(dependent)
+            .expect("one failure collapsed the project depending on it");
+        assert!(dependent.contains::<Weakened>());
+    }
+
+    #[test]
+    fn dependencies_link_described_blocks_in_their_level() {
+        for level in LEVELS {
+            let (blocks, _) = (level.layout)();
+            let described: Vec<&str> = blocks.iter().filter_map(|b| b.description).collect();
+            for dependency in level.dependencies {
+                assert_ne!(dependency.project, dependency.depends_on);
+                for id in [dependency.project, dependency.depends_on] {
+                    assert!(
+                        described.contains(&id),
+                        "level {} has a dependency on {id}, which no block describes",
+                        level.id
+                    );
+                }
+            }
+        }
+    }
+}
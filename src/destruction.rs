@@ -0,0 +1,244 @@
+// Destruction rules: fast birds break the blocks they hit and fast-moving pigs are defeated.
+// Also reports hard hits between any two bodies, for sound and effects.
+use avian2d::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    content::ContentDiscovered,
+    level::{Block, BlockDescription, BlockMaterial, Invisible, Pig, PigType},
+    slingshot::Bird,
+};
+
+// Speeds (pixels/s) a bird must be going to break a block, and a pig to be defeated
+pub const WOOD_BREAK_SPEED: f32 = 600.0;
+pub const STEEL_BREAK_SPEED: f32 = 800.0;
+pub const PIG_DEFEAT_SPEED: f32 = 600.0;
+// Break speed multiplier for a Weakened block
+pub const WEAKENED_FACTOR: f32 = 0.5;
+const WEAKENED_TINT: Color = Color::srgb(0.75, 0.5, 0.9);
+// Collision impulses (mass × pixels/s): below IMPACT_IMPULSE bodies are settling rather
+// than hitting anything, and at HEAVY_IMPULSE a hit sounds and looks as big as it gets
+pub const IMPACT_IMPULSE: f32 = 20_000.0;
+pub const HEAVY_IMPULSE: f32 = 2_000_000.0;
+
+// A block damaged by a disaster or a failed dependency, which now breaks at lower speeds
+#[derive(Component)]
+pub struct Weakened;
+
+// Sent when a described block is destroyed, by a hit or otherwise
+#[derive(Message)]
+pub struct ProjectDestroyed(pub &'static str);
+
+// Sent when a bird breaks a block, for sound and effects
+#[derive(Message)]
+pub struct BlockBroken {
+    pub material: BlockMaterial,
+    pub position: Vec2,
+}
+
+// Sent when a pig is defeated, for sound and effects
+#[derive(Message)]
+pub struct PigDefeated {
+    pub pig_type: PigType,
+    pub position: Vec2,
+}
+
+// What each side of an impact is made of. Declared in order of precedence, so a wooden
+// block hitting a steel one sounds like steel.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Surface {
+    Steel,
+    Wood,
+    // Birds and pigs
+    Creature,
+    // The ground, and the invisible supports standing in for it
+    Ground,
+}
+
+// Sent when two bodies start touching at IMPACT_IMPULSE or more
+#[derive(Message)]
+pub struct Impact {
+    pub position: Vec2,
+    pub impulse: f32,
+    pub surfaces: [Surface; 2],
+    pub entities: [Entity; 2],
+}
+
+pub struct DestructionPlugin;
+
+impl Plugin for DestructionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ContentDiscovered>()
+            .add_message::<ProjectDestroyed>()
+            .add_message::<BlockBroken>()
+            .add_message::<PigDefeated>()
+            .add_message::<Impact>()
+            .add_systems(
+                Update,
+                (
+                    block_destruction_system,
+                    pig_destruction_system,
+                    impact_system,
+                ),
+            );
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn pig_destruction_system(
+    mut commands: Commands,
+    mut defeated: MessageWriter<PigDefeated>,
+    pig_q: Query<
+        (
+            Entity,
+            &PigType,
+            &Transform,
+            &LinearVelocity,
+            &CollidingEntities,
+        ),
+        With<Pig>,
+    >,
+) {
+    for (entity, pig_type, transform, velocity, colliding_entities) in pig_q.iter() {
+        if !colliding_entities.is_empty() && velocity.length() > PIG_DEFEAT_SPEED {
+            commands.entity(entity).despawn();
+            defeated.write(PigDefeated {
+                pig_type: *pig_type,
+                position: transform.translation.truncate(),
+            });
+        }
+    }
+}
+
+// Everything that goes with a block breaking, whatever broke it: the block goes, the
+// project it stands for is discovered and destroyed, score, sound and effects hear about
+// it, and the invisible supports come down. Call `finish` once a system is done breaking.
+#[derive(SystemParam)]
+pub struct BlockBreaker<'w, 's> {
+    commands: Commands<'w, 's>,
+    discovered: MessageWriter<'w, ContentDiscovered>,
+    destroyed: MessageWriter<'w, ProjectDestroyed>,
+    broken: MessageWriter<'w, BlockBroken>,
+    block_q: Query<
+        'w,
+        's,
+        (
+            &'static Transform,
+            &'static BlockMaterial,
+            Option<&'static BlockDescription>,
+        ),
+        With<Block>,
+    >,
+    invisible_q: Query<'w, 's, Entity, With<Invisible>>,
+    // Broken since the last `finish`, so a block hit twice in one frame only breaks once
+    broken_now: Local<'s, Vec<Entity>>,
+}
+
+impl BlockBreaker<'_, '_> {
+    pub fn break_block(&mut self, entity: Entity) {
+        if self.broken_now.contains(&entity) {
+            return;
+        }
+        let Ok((transform, material, description)) = self.block_q.get(entity) else {
+            return;
+        };
+        self.broken_now.push(entity);
+        self.commands.entity(entity).try_despawn();
+        self.broken.write(BlockBroken {
+            material: *material,
+            position: transform.translation.truncate(),
+        });
+        if let Some(description) = description {
+            self.discovered.write(ContentDiscovered(description.0));
+            self.destroyed.write(ProjectDestroyed(description.0));
+        }
+    }
+
+    pub fn finish(&mut self) {
+        if !self.broken_now.is_empty() {
+            remove_invisible_supports(&mut self.commands, &self.invisible_q);
+            self.broken_now.clear();
+        }
+    }
+}
+
+pub fn block_destruction_system(
+    mut breaker: BlockBreaker,
+    block_q: Query<(&BlockMaterial, Has<Weakened>), With<Block>>,
+    bird_q: Query<(&LinearVelocity, &CollidingEntities), With<Bird>>,
+) {
+    for (velocity, colliding_entities) in bird_q.iter() {
+        let mag = velocity.length();
+        for &hit_entity in colliding_entities.iter() {
+            if let Ok((material, weakened)) = block_q.get(hit_entity)
+                && mag > break_speed(*material, weakened)
+            {
+                breaker.break_block(hit_entity);
+            }
+        }
+    }
+    breaker.finish();
+}
+
+// The invisible supports only hold a level up until something in it breaks. A hit and a
+// cascade can both remove them in the same frame.
+pub fn remove_invisible_supports(
+    commands: &mut Commands,
+    invisible_q: &Query<Entity, With<Invisible>>,
+) {
+    for entity in invisible_q.iter() {
+        commands.entity(entity).try_despawn();
+    }
+}
+
+pub fn impact_system(
+    mut impacts: MessageWriter<Impact>,
+    collisions: Collisions,
+    material_q: Query<&BlockMaterial>,
+    creature_q: Query<(), Or<(With<Pig>, With<Bird>)>>,
+) {
+    let surface = |entity: Entity| match material_q.get(entity) {
+        Ok(BlockMaterial::Steel) => Surface::Steel,
+        Ok(BlockMaterial::Wood) => Surface::Wood,
+        _ if creature_q.contains(entity) => Surface::Creature,
+        _ => Surface::Ground,
+    };
+
+    for contacts in collisions.iter() {
+        let impulse = contacts.total_normal_impulse_magnitude();
+        if !contacts.collision_started() || impulse < IMPACT_IMPULSE {
+            continue;
+        }
+        let mut points = contacts
+            .manifolds
+            .iter()
+            .flat_map(|manifold| manifold.points.iter());
+        if let Some(point) = points.next() {
+            impacts.write(Impact {
+                position: point.point,
+                impulse,
+                surfaces: [surface(contacts.collider1), surface(contacts.collider2)],
+                entities: [contacts.collider1, contacts.collider2],
+            });
+        }
+    }
+}
+
+pub fn break_speed(material: BlockMaterial, weakened: bool) -> f32 {
+    let speed = match material {
+        BlockMaterial::Steel => STEEL_BREAK_SPEED,
+        _ => WOOD_BREAK_SPEED,
+    };
+    if weakened {
+        speed * WEAKENED_FACTOR
+    } else {
+        speed
+    }
+}
+
+// Tinted so the player can see the damage
+pub fn weaken(commands: &mut Commands, block: Entity, sprite: &mut Sprite) {
+    // The block may be breaking this frame too
+    commands.entity(block).try_insert(Weakened);
+    sprite.color = WEAKENED_TINT;
+}
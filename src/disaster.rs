@@ -0,0 +1,156 @@
+// Disasters: each pig on the slingshot stands for a real open-source incident, and when it
+// first hits something after launch the incident plays out on the level.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+use crate::{
+    content::ContentDiscovered,
+    destruction::{BlockBreaker, BlockBroken, ProjectDestroyed, block_destruction_system, weaken},
+    level::{Block, BlockMaterial, Invisible},
+    slingshot::{Bird, OnSlingshot, SlingshotState},
+};
+
+// Blocks within this distance (pixels) of an Overload impact are thrown outwards, at up to
+// OVERLOAD_SPEED for one right next to it
+const OVERLOAD_RADIUS: f32 = 250.0;
+const OVERLOAD_SPEED: f32 = 500.0;
+
+pub struct DisasterPlugin;
+
+impl Plugin for DisasterPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<Blast>()
+            .add_message::<ContentDiscovered>()
+            .add_message::<ProjectDestroyed>()
+            .add_message::<BlockBroken>()
+            .add_systems(
+                Update,
+                (
+                    arm_disaster_system,
+                    // Blocks the same hit broke are already gone, so they can't be stolen too
+                    disaster_impact_system.after(block_destruction_system),
+                )
+                    .chain(),
+            );
+    }
+}
+
+// An incident: the content entry describing it and what it does on impact
+#[derive(Clone, Copy, PartialEq)]
+pub struct Disaster {
+    pub content: &'static str,
+    pub effect: DisasterEffect,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DisasterEffect {
+    // XZ: the block hit is quietly compromised and breaks more easily
+    Corrupt,
+    // Redis: the block hit is wrapped up and carried off
+    Steal,
+    // OpenSSL: underfunding weakens every steel block at once
+    DrainSteel,
+    // FFMPEG: an urgent demand shakes everything nearby
+    Overload,
+}
+
+impl DisasterEffect {
+    // String key describing the effect to the player
+    pub fn description_key(self) -> &'static str {
+        match self {
+            DisasterEffect::Corrupt => "disaster.corrupt",
+            DisasterEffect::Steal => "disaster.steal",
+            DisasterEffect::DrainSteel => "disaster.drain-steel",
+            DisasterEffect::Overload => "disaster.overload",
+        }
+    }
+}
+
+// Sent when an Overload goes off, which hits like an explosion
+#[derive(Message)]
+pub struct Blast {
+    pub position: Vec2,
+}
+
+// Carried by a launched bird until its first impact
+#[derive(Component)]
+pub struct CarriedDisaster(pub DisasterEffect);
+
+// Gives a bird the disaster shown in the slingshot as it's launched
+fn arm_disaster_system(
+    mut commands: Commands,
+    slingshot: Res<SlingshotState>,
+    mut launched: RemovedComponents<OnSlingshot>,
+    bird_q: Query<(), With<Bird>>,
+) {
+    for entity in launched.read() {
+        if bird_q.contains(entity)
+            && let Some(disaster) = slingshot.disaster
+        {
+            commands
+                .entity(entity)
+                .insert(CarriedDisaster(disaster.effect));
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn disaster_impact_system(
+    mut commands: Commands,
+    mut breaker: BlockBreaker,
+    mut blasts: MessageWriter<Blast>,
+    bird_q: Query<(Entity, &Transform, &CollidingEntities, &CarriedDisaster), With<Bird>>,
+    mut block_q: Query<
+        (
+            Entity,
+            &Transform,
+            &BlockMaterial,
+            &mut Sprite,
+            &mut LinearVelocity,
+        ),
+        (With<Block>, Without<Invisible>, Without<Bird>),
+    >,
+) {
+    for (bird, bird_transform, colliding, disaster) in bird_q.iter() {
+        if colliding.is_empty() {
+            continue;
+        }
+        commands.entity(bird).remove::<CarriedDisaster>();
+        let hit_block = colliding.iter().copied().find(|&e| block_q.contains(e));
+        let impact = bird_transform.translation.truncate();
+
+        match disaster.0 {
+            DisasterEffect::Corrupt => {
+                if let Some(block) = hit_block
+                    && let Ok((_, _, _, mut sprite, _)) = block_q.get_mut(block)
+                {
+                    weaken(&mut commands, block, &mut sprite);
+                }
+            }
+            DisasterEffect::Steal => {
+                if let Some(block) = hit_block {
+                    breaker.break_block(block);
+                }
+            }
+            DisasterEffect::DrainSteel => {
+                for (block, _, material, mut sprite, _) in block_q.iter_mut() {
+                    if *material == BlockMaterial::Steel {
+                        weaken(&mut commands, block, &mut sprite);
+                    }
+                }
+            }
+            DisasterEffect::Overload => {
+                blasts.write(Blast { position: impact });
+                for (_, transform, _, _, mut velocity) in block_q.iter_mut() {
+                    let offset = transform.translation.truncate() - impact;
+                    let distance = offset.length();
+                    if distance < OVERLOAD_RADIUS {
+                        let strength = 1.0 - distance / OVERLOAD_RADIUS;
+                        velocity.0 += offset.normalize_or(Vec2::Y) * OVERLOAD_SPEED * strength;
+                    }
+                }
+            }
+        }
+    }
+    breaker.finish();
+}
@@ -0,0 +1,255 @@
+// The encyclopedia: every project and disaster the player has come across, collected by
+// hovering or destroying described blocks and launching pigs, and saved between runs.
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::ContentDiscovered,
+    level::LEVELS,
+    locale::Localizer,
+    slingshot::{Bird, OnSlingshot, SlingshotState},
+    ui::content_entry_ui,
+};
+
+// Relative to the working directory; browser builds use it as their localStorage key
+const SAVE_PATH: &str = "encyclopedia.json";
+pub const TOGGLE_KEY: KeyCode = KeyCode::KeyE;
+
+pub struct EncyclopediaPlugin;
+
+impl Plugin for EncyclopediaPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ContentDiscovered>()
+            .insert_resource(load_encyclopedia())
+            .insert_resource(EncyclopediaIndex(all_content_ids()))
+            .init_resource::<EncyclopediaPanel>()
+            .add_systems(
+                Update,
+                (
+                    launch_unlock_system,
+                    unlock_system,
+                    save_encyclopedia_system.run_if(
+                        resource_changed::<Encyclopedia>.and(not(resource_added::<Encyclopedia>)),
+                    ),
+                    toggle_panel_system,
+                )
+                    .chain(),
+            )
+            .add_systems(EguiPrimaryContextPass, encyclopedia_ui_system);
+    }
+}
+
+// Content ids unlocked so far, in the order they were found
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct Encyclopedia {
+    unlocked: Vec<String>,
+}
+
+impl Encyclopedia {
+    pub fn is_unlocked(&self, id: &str) -> bool {
+        self.unlocked.iter().any(|unlocked| unlocked == id)
+    }
+
+    // Returns whether this was a new find
+    pub fn unlock(&mut self, id: &str) -> bool {
+        if self.is_unlocked(id) {
+            return false;
+        }
+        self.unlocked.push(id.to_string());
+        true
+    }
+
+    pub fn unlocked_count(&self) -> usize {
+        self.unlocked.len()
+    }
+}
+
+// Every content id the shipped levels use, so locked entries can be listed too
+#[derive(Resource)]
+pub struct EncyclopediaIndex(pub Vec<&'static str>);
+
+#[derive(Resource, Default)]
+pub struct EncyclopediaPanel {
+    pub open: bool,
+    selected: Option<&'static str>,
+}
+
+fn all_content_ids() -> Vec<&'static str> {
+    let mut ids = Vec::new();
+    for level in LEVELS {
+        let (blocks, _) = (level.layout)();
+        let level_ids = blocks
+            .iter()
+            .filter_map(|block| block.description)
+            .chain(level.disasters.iter().map(|disaster| disaster.content));
+        for id in level_ids {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    ids
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_encyclopedia() -> Encyclopedia {
+    std::fs::read(SAVE_PATH)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_encyclopedia() -> Encyclopedia {
+    local_storage()
+        .and_then(|storage| storage.get_item(SAVE_PATH).ok()?)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_encyclopedia_system(encyclopedia: Res<Encyclopedia>) {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let result = serde_json::to_vec_pretty(&*encyclopedia)
+            .map_err(std::io::Error::other)
+            .and_then(|json| std::fs::write(SAVE_PATH, json));
+        if let Err(err) = result {
+            warn!("Couldn't save the encyclopedia to {SAVE_PATH}: {err}");
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let result = serde_json::to_string(&*encyclopedia)
+            .map_err(|err| err.to_string())
+            .and_then(|json| {
+                let storage = local_storage().ok_or("no localStorage")?;
+                storage
+                    .set_item(SAVE_PATH, &json)
+                    .map_err(|err| format!("{err:?}"))
+            });
+        if let Err(err) = result {
+            warn!("Couldn't save the encyclopedia to localStorage: {err}");
+        }
+    }
+}
+
+// A launched pig reveals the disaster it stood for
+fn launch_unlock_system(
+    mut launched: RemovedComponents<OnSlingshot>,
+    mut discovered: MessageWriter<ContentDiscovered>,
+    slingshot: Res<SlingshotState>,
+    bird_q: Query<(), With<Bird>>,
+) {
+    for entity in launched.read() {
+        if bird_q.contains(entity)
+            && let Some(disaster) = slingshot.disaster
+        {
+            discovered.write(ContentDiscovered(disaster.content));
+        }
+    }
+}
+
+fn unlock_system(
+    mut discovered: MessageReader<ContentDiscovered>,
+    mut encyclopedia: ResMut<Encyclopedia>,
+) {
+    for ContentDiscovered(id) in discovered.read() {
+        // Only touch the resource for new finds, so it's saved only when something changed
+        if !encyclopedia.is_unlocked(id) {
+            encyclopedia.unlock(id);
+        }
+    }
+}
+
+fn toggle_panel_system(keyboard: Res<ButtonInput<KeyCode>>, mut panel: ResMut<EncyclopediaPanel>) {
+    if keyboard.just_pressed(TOGGLE_KEY) {
+        panel.open = !panel.open;
+    }
+}
+
+// Every entry, locked ones hidden, with the selected entry's full text alongside
+pub fn encyclopedia_ui_system(
+    mut contexts: EguiContexts,
+    mut panel: ResMut<EncyclopediaPanel>,
+    encyclopedia: Res<Encyclopedia>,
+    index: Res<EncyclopediaIndex>,
+    locale: Localizer,
+) {
+    if !panel.open {
+        return;
+    }
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let mut open = panel.open;
+    egui::Window::new(locale.tr("encyclopedia.title"))
+        .id(egui::Id::new("encyclopedia"))
+        .open(&mut open)
+        .default_size((520.0, 360.0))
+        .show(ctx, |ui| {
+            ui.label(locale.tr_args(
+                "encyclopedia.progress",
+                &[
+                    ("found", &encyclopedia.unlocked_count()),
+                    ("total", &index.0.len()),
+                ],
+            ));
+            ui.separator();
+            ui.columns(2, |columns| {
+                egui::ScrollArea::vertical()
+                    .id_salt("encyclopedia_list")
+                    .show(&mut columns[0], |ui| {
+                        for &id in &index.0 {
+                            let unlocked = encyclopedia.is_unlocked(id);
+                            let title = match locale.content(id) {
+                                Some(entry) if unlocked => entry.title.clone(),
+                                _ => locale.tr("encyclopedia.locked"),
+                            };
+                            let response = ui.add_enabled(
+                                unlocked,
+                                egui::Button::selectable(panel.selected == Some(id), title),
+                            );
+                            if response.clicked() {
+                                panel.selected = Some(id);
+                            }
+                        }
+                    });
+                let selected = panel
+                    .selected
+                    .filter(|id| encyclopedia.is_unlocked(id))
+                    .and_then(|id| locale.content(id));
+                egui::ScrollArea::vertical()
+                    .id_salt("encyclopedia_entry")
+                    .show(&mut columns[1], |ui| match selected {
+                        Some(entry) => content_entry_ui(ui, entry, &locale),
+                        None => {
+                            ui.label(locale.tr("encyclopedia.pick"));
+                        }
+                    });
+            });
+        });
+    panel.open = open;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlocks_are_kept_once_and_survive_a_save() {
+        let mut encyclopedia = Encyclopedia::default();
+        assert!(encyclopedia.unlock("git"));
+        assert!(encyclopedia.unlock("xz-backdoor"));
+        assert!(!encyclopedia.unlock("git"));
+
+        let json = serde_json::to_string(&encyclopedia).unwrap();
+        let loaded: Encyclopedia = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.unlocked, ["git", "xz-backdoor"]);
+    }
+}
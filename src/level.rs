@@ -0,0 +1,858 @@
+// Levels: the block and pig components, the shipped level layouts and spawning them.
+use avian2d::{math::Vector, prelude::*};
+use bevy::prelude::*;
+
+use crate::{
+    dependency::Dependency,
+    destruction::{BlockBroken, PigDefeated},
+    disaster::{Disaster, DisasterEffect},
+    menu::LoadLevel,
+};
+
+// Scaled so the game's pixel units fall like metres
+pub const GRAVITY: Vec2 = Vec2::new(0.0, -9.8 * 100.0);
+// Points for each target defeated and each block broken
+pub const PIG_POINTS: u32 = 5000;
+pub const BLOCK_POINTS: u32 = 500;
+
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Gravity(GRAVITY))
+            .init_resource::<CurrentLevel>()
+            .init_resource::<Score>()
+            .add_message::<PigDefeated>()
+            .add_message::<BlockBroken>()
+            .add_message::<LoadLevel>()
+            .add_systems(Startup, setup_level)
+            .add_systems(Update, score_system);
+    }
+}
+
+#[derive(Component)]
+pub struct Pig;
+
+#[derive(Component)]
+pub struct Block;
+
+#[derive(Component)]
+pub struct Invisible;
+
+// Content id of what a block stands for, shown when it's hovered
+#[derive(Component)]
+pub struct BlockDescription(pub &'static str);
+
+// Index into LEVELS of the level being played
+#[derive(Resource, Default)]
+pub struct CurrentLevel(pub usize);
+
+// Points earned on the current level, reset when it's loaded again
+#[derive(Resource, Default)]
+pub struct Score(pub u32);
+
+fn score_system(
+    mut score: ResMut<Score>,
+    mut load_level: MessageReader<LoadLevel>,
+    mut defeated: MessageReader<PigDefeated>,
+    mut broken: MessageReader<BlockBroken>,
+) {
+    // Anything knocked over just before a reload belongs to the old try
+    if load_level.read().count() > 0 {
+        score.0 = 0;
+        defeated.clear();
+        broken.clear();
+        return;
+    }
+    score.0 += defeated.read().count() as u32 * PIG_POINTS;
+    score.0 += broken.read().count() as u32 * BLOCK_POINTS;
+}
+
+pub fn setup_level(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    current_level: Res<CurrentLevel>,
+) {
+    // Pause time to view structure
+    // time.pause();
+
+    // Background
+    commands.spawn((
+        Sprite::from_image(asset_server.load("background.png")),
+        Transform::from_xyz(0.0, 0.0, -10.0).with_scale(Vec3::splat(4.0)),
+    ));
+
+    spawn_ground(&mut commands);
+
+    // Complex Tower
+    spawn_level(&mut commands, &asset_server, &LEVELS[current_level.0]);
+}
+
+pub fn spawn_ground(commands: &mut Commands) -> Entity {
+    commands
+        .spawn((
+            Sprite::from_color(Color::srgb(0.2, 0.8, 0.2), Vec2::new(10000.0, 50.0)),
+            Transform::from_xyz(0.0, -300.0, 0.0),
+            RigidBody::Static,
+            Collider::rectangle(10000.0, 50.0),
+        ))
+        .id()
+}
+
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum BlockMaterial {
+    Wood,
+    Steel,
+    Invisible,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum BlockShape {
+    SquareLarge,
+    SquareMedium,
+    SquareSmall,
+    LongBeam,
+    ShortBeam,
+    Triangle,
+}
+
+pub struct BlockCreator {
+    pub material: BlockMaterial,
+    pub shape: BlockShape,
+    pub pos: Vec2,
+    pub rotation: Quat,
+    // Content id, see BlockDescription
+    pub description: Option<&'static str>,
+}
+
+pub struct PigCreator {
+    pub pos: Vec2,
+    pub pig_type: PigType,
+}
+
+pub struct Level {
+    // Short name used to pick the level on the command line
+    pub id: &'static str,
+    // English name, for the command-line tools
+    pub name: &'static str,
+    // String key of the name shown in the game
+    pub name_key: &'static str,
+    // Area the camera may show, in world units
+    pub bounds: Rect,
+    pub layout: fn() -> (Vec<BlockCreator>, Vec<PigCreator>),
+    // Pigs the player gets to launch; each after the first stands for a random disaster
+    pub birds: usize,
+    // The disasters a pig on the slingshot can stand for
+    pub disasters: &'static [Disaster],
+    // Which described blocks are built on which, see `dependency`
+    pub dependencies: &'static [Dependency],
+}
+
+// Every level that ships with the game, in play order
+pub const LEVELS: &[Level] = &[Level {
+    id: "internet",
+    name: "What's Holding up the Internet?",
+    name_key: "level.internet",
+    bounds: Rect {
+        min: Vec2::new(-900.0, -600.0),
+        max: Vec2::new(1300.0, 900.0),
+    },
+    layout: get_game_layout,
+    birds: 6,
+    disasters: &[
+        Disaster {
+            content: "xz-backdoor",
+            effect: DisasterEffect::Corrupt,
+        },
+        Disaster {
+            content: "ffmpeg-microsoft",
+            effect: DisasterEffect::Overload,
+        },
+        Disaster {
+            content: "redis-amazon",
+            effect: DisasterEffect::Steal,
+        },
+        Disaster {
+            content: "openssl-funding",
+            effect: DisasterEffect::DrainSteel,
+        },
+    ],
+    // Everything is compiled with LLVM or GCC, and the servers run on Linux
+    dependencies: &[
+        Dependency {
+            project: "linux",
+            depends_on: "llvm-gcc",
+        },
+        Dependency {
+            project: "openssl",
+            depends_on: "llvm-gcc",
+        },
+        Dependency {
+            project: "ffmpeg",
+            depends_on: "llvm-gcc",
+        },
+        Dependency {
+            project: "pytorch",
+            depends_on: "llvm-gcc",
+        },
+        Dependency {
+            project: "pytorch",
+            depends_on: "linux",
+        },
+        Dependency {
+            project: "git",
+            depends_on: "linux",
+        },
+        Dependency {
+            project: "redis",
+            depends_on: "linux",
+        },
+        Dependency {
+            project: "redis",
+            depends_on: "openssl",
+        },
+        Dependency {
+            project: "postgresql",
+            depends_on: "linux",
+        },
+        Dependency {
+            project: "postgresql",
+            depends_on: "openssl",
+        },
+    ],
+}];
+
+pub fn get_game_layout() -> (Vec<BlockCreator>, Vec<PigCreator>) {
+    let mut blocks = Vec::new();
+    let mut pigs = Vec::new();
+
+    let center_x = 300.0;
+    let ground_top = -275.0;
+
+    // --- The "Thanklessly Maintaining" Base ---
+    // Right: Single vertical stone block holding up the right side (The unstable part)
+    // Using ShortBeam (83x41) rotated 90 degrees -> 41 wide, 83 tall
+    let right_support_h = 83.0;
+    let right_support_pos = Vec2::new(center_x + 120.0, ground_top + right_support_h / 2.0);
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Steel,
+        shape: BlockShape::ShortBeam,
+        pos: right_support_pos,
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: Some("ffmpeg"),
+    });
+
+    // Left: A solid base of stone
+    // Using SquareLarge (82x82)
+    let middle_support_h = 83.0;
+    let left_support_pos = Vec2::new(center_x, ground_top + middle_support_h / 2.0);
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Steel,
+        shape: BlockShape::ShortBeam,
+        pos: left_support_pos,
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: Some("openssl"),
+    });
+
+    // Left: A solid base of stone
+    // Using SquareLarge (82x82)
+    let left_support_h = 82.0;
+    let left_support_pos = Vec2::new(center_x - 120.0, ground_top + left_support_h / 2.0);
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Steel,
+        shape: BlockShape::SquareLarge,
+        pos: left_support_pos,
+        rotation: Quat::IDENTITY,
+        description: Some("linux"),
+    });
+
+    // --- The Main Floor Plank ---
+    // Spanning across the two supports.
+    // We need a very long span. Let's use two LongBeams (167x20) overlapping or end-to-end.
+    // Supports are at -120 and +120 (dist 240). LongBeam is 167.
+    // Let's put one centered, but it might fall.
+    // Let's put two LongBeams side by side to make a wide platform.
+    let floor_y = ground_top + right_support_h + 10.0; // Sitting on top of the 83.0 high support
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(center_x - 80.0, floor_y),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(center_x + 80.0, floor_y),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // Left Wing
+    // These blocks are placed relative to the right tower's x-coordinate,
+    // but at the same y-level as the main floor planks.
+    // Assuming 'right_tower_x' is intended to be 'center_x' for these wings,
+    // or that these are meant to be part of the main floor structure.
+    // Given the instruction "Add descriptions to the wing blocks", and the provided
+    // code snippet, it seems these are new blocks.
+    // The original code does not define `l4_y` at this point, so we'll use `floor_y`.
+    // The `right_tower_x` variable is defined later, so we'll use `center_x` for now,
+    // or assume these are meant to be placed relative to the main structure.
+    // For faithfulness to the instruction, we'll use `center_x` for placement
+    // and `floor_y` for height, as `l4_y` is not defined here.
+
+    // Even more unstable layer
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Steel,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(center_x - 50.0, floor_y + 10.0 + 83.0 / 2.0),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: Some("pytorch"),
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Steel,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(center_x + 50.0, floor_y + 10.0 + 83.0 / 2.0),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: Some("llvm-gcc"),
+    });
+
+    // invisible supports
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(center_x, floor_y + 10.0 + 83.0 / 2.0),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(center_x + 120.0, floor_y + 10.0 + 83.0 / 2.0),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(center_x - 120.0, floor_y + 10.0 + 83.0 / 2.0),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+
+    // floor of the unstable layer (slight gap is to allow a metal box to "pin down")
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(center_x - 80.0 - 7.0, floor_y + 83.0 + 10.0 + 10.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(center_x + 80.0 + 5.0, floor_y + 83.0 + 10.0 + 10.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // --- Left Tower (The "Box") ---
+    // Sitting on the left side of the floor.
+    let left_tower_x = center_x - 90.0;
+    let mut current_y = floor_y + 10.0 + 83.0 + 20.0; // Top of floor
+
+    // Walls: Vertical LongBeams (167 tall)
+    let wall_h = 167.0;
+    let wall_y = current_y + wall_h / 2.0;
+
+    // Left Wall
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(left_tower_x - 70.0, wall_y),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+    // Right Wall (Shared with middle?)
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(left_tower_x + 70.0, wall_y),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+
+    // Shelves inside the box (The "Glass" windows replaced by Wood)
+    // Lower Shelf
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(left_tower_x, current_y + 40.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // Upper Shelf
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(left_tower_x, current_y + 100.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+    // Pig on upper shelf
+    pigs.push(PigCreator {
+        pos: Vec2::new(left_tower_x, current_y + 100.0 + 20.0 + 23.0),
+        pig_type: PigType::BombBird,
+    });
+
+    // Ceiling of Left Tower
+    let ceiling_y = current_y + wall_h + 10.0;
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood, // Stone slab on top
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(left_tower_x, ceiling_y),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // Left Tower "jutting out"
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Steel, // Stone slab on top
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(left_tower_x - 50.0, ceiling_y + 10.0 + 83.0 / 2.0),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: Some("git"),
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood, // Stone slab on top
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(left_tower_x - 50.0, ceiling_y + 10.0 + 10.0 + 83.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    let jutted_floor_x = left_tower_x - 50.0;
+    let jutted_floor_y = ceiling_y + 10.0 + 10.0 + 83.0;
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Steel,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(
+            jutted_floor_x - 163.0 / 2.0 + 10.0,
+            jutted_floor_y + 81.0 / 2.0 + 10.0,
+        ),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: Some("redis"),
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(jutted_floor_x - 163.0 / 2.0 + 10.0, jutted_floor_y - 20.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::SquareLarge,
+        pos: Vec2::new(
+            jutted_floor_x + 163.0 / 2.0 - 10.0,
+            jutted_floor_y + 81.0 / 2.0 + 10.0,
+        ),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(
+            jutted_floor_x + 163.0 / 2.0 - 10.0 - 163.0,
+            jutted_floor_y + 81.0 + 10.0 + 10.0,
+        ),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(jutted_floor_x - 163.0 - 10.0, jutted_floor_y + 81.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(
+            jutted_floor_x + 163.0 / 2.0 - 10.0 - 326.0,
+            jutted_floor_y + 81.0 + 10.0 + 10.0,
+        ),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(jutted_floor_x - 163.0 - 163.0, jutted_floor_y + 81.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // King Pig at the top
+    pigs.push(PigCreator {
+        pos: Vec2::new(jutted_floor_x - 81.0, jutted_floor_y + 81.0 + 90.0 + 10.0),
+        pig_type: PigType::King,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(
+            jutted_floor_x - 81.0 + 81.0,
+            jutted_floor_y + 81.0 + 90.0 + 10.0,
+        ),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(
+            jutted_floor_x - 81.0 - 81.0,
+            jutted_floor_y + 81.0 + 90.0 + 10.0,
+        ),
+
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Steel,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(
+            jutted_floor_x + 163.0 / 2.0 - 10.0 - 326.0 - 66.0,
+            jutted_floor_y + 81.0 + 10.0 + 10.0 + 41.0 + 10.0,
+        ),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: Some("postgresql"),
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(
+            jutted_floor_x + 163.0 / 2.0 - 10.0 - 326.0 - 66.0,
+            jutted_floor_y + 81.0 + 10.0 + 10.0 + 41.0 + 10.0 + 41.0,
+        ),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // King Pig at the top
+    pigs.push(PigCreator {
+        pos: Vec2::new(
+            jutted_floor_x + 163.0 / 2.0 - 10.0 - 326.0 - 66.0,
+            jutted_floor_y + 81.0 + 10.0 + 10.0 + 41.0 + 10.0 + 41.0 + 85.0,
+        ),
+        pig_type: PigType::King,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(
+            jutted_floor_x + 163.0 / 2.0 - 10.0 - 326.0 - 66.0 - 86.0,
+            jutted_floor_y + 81.0 + 10.0 + 10.0 + 41.0 + 10.0 + 41.0 + 85.0,
+        ),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(
+            jutted_floor_x + 163.0 / 2.0 - 10.0 - 326.0 - 66.0 + 86.0,
+            jutted_floor_y + 81.0 + 10.0 + 10.0 + 41.0 + 10.0 + 41.0 + 85.0,
+        ),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // --- Right Tower (The Tall Unstable One) ---
+    let right_tower_x = center_x + 100.0;
+    current_y = floor_y + 10.0 + 83.0 + 20.0;
+
+    // Level 1: Vertical Wood Beams (Short or Long? Image looks like stacked frames)
+    // Let's use Vertical ShortBeams (83 tall) for a more segmented look
+    let l1_h = 83.0;
+    let l1_y = current_y + l1_h / 2.0;
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(right_tower_x - 50.0, l1_y),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(right_tower_x + 50.0, l1_y),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+
+    // Pig in Level 1
+    pigs.push(PigCreator {
+        pos: Vec2::new(right_tower_x, current_y + 23.0),
+        pig_type: PigType::TriangleBird,
+    });
+
+    // Level 1 Ceiling
+    current_y += l1_h + 10.0;
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(right_tower_x, current_y),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // Level 2: More Vertical Beams (The "Glass" part replaced by Wood)
+    // Using LongBeams vertical here for height
+    let l2_h = 167.0;
+    let l2_y = current_y + 10.0 + l2_h / 2.0;
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(right_tower_x - 40.0, l2_y),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(right_tower_x + 40.0, l2_y),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+
+    // Pig in Level 2
+    pigs.push(PigCreator {
+        pos: Vec2::new(right_tower_x, current_y + 10.0 + 23.0),
+        pig_type: PigType::BlueBird,
+    });
+
+    // Level 2 Ceiling
+    current_y += 10.0 + l2_h + 10.0;
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::LongBeam,
+        pos: Vec2::new(right_tower_x, current_y),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // Level 3: The "Penthouse"
+    // Short vertical beams
+    let l3_h = 83.0;
+    let l3_y = current_y + 10.0 + l3_h / 2.0;
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(right_tower_x - 30.0, l3_y),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(right_tower_x + 30.0, l3_y),
+        rotation: Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+        description: None,
+    });
+
+    // King Pig at the top
+    pigs.push(PigCreator {
+        pos: Vec2::new(right_tower_x, l3_y + 10.0 + 23.0 + 70.0 + 50.0),
+        pig_type: PigType::King,
+    });
+
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Invisible,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(right_tower_x + 84.0, l3_y + 10.0 + 23.0 + 70.0 + 50.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // Level 3 Ceiling (Stone)
+    current_y += 10.0 + l3_h + 10.0;
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::ShortBeam,
+        pos: Vec2::new(right_tower_x, current_y),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    // Top Crown
+    // A couple of small blocks balancing
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(right_tower_x - 25.0, current_y + 10.0 + 10.0 + 5.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+    blocks.push(BlockCreator {
+        material: BlockMaterial::Wood,
+        shape: BlockShape::SquareSmall,
+        pos: Vec2::new(right_tower_x + 25.0, current_y + 10.0 + 10.0 + 5.0),
+        rotation: Quat::IDENTITY,
+        description: None,
+    });
+
+    (blocks, pigs)
+}
+
+pub fn spawn_level(commands: &mut Commands, asset_server: &Res<AssetServer>, level: &Level) {
+    let (blocks, pigs) = (level.layout)();
+    for block in blocks {
+        spawn_block(
+            commands,
+            asset_server,
+            block.material,
+            block.shape,
+            block.pos,
+            block.rotation,
+            block.description,
+        );
+    }
+
+    for pig in pigs {
+        spawn_pig(commands, asset_server, pig.pig_type, pig.pos);
+    }
+}
+
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum PigType {
+    King,
+    Normal,
+    RedBird,
+    BombBird,
+    TriangleBird,
+    EggBird,
+    BlueBird,
+}
+
+pub fn spawn_block(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    material: BlockMaterial,
+    shape: BlockShape,
+    pos: Vec2,
+    rotation: Quat,
+    description: Option<&'static str>,
+) {
+    let material_str = match material {
+        BlockMaterial::Steel => "steel/steel",
+        BlockMaterial::Wood => "wood/wood",
+        BlockMaterial::Invisible => "",
+    };
+
+    let shape_str = match shape {
+        BlockShape::SquareLarge => "square_large",
+        BlockShape::SquareMedium => "square_medium",
+        BlockShape::SquareSmall => "square_small",
+        BlockShape::LongBeam => "beam_long",
+        BlockShape::ShortBeam => "beam_short",
+        BlockShape::Triangle => "triangle",
+    };
+
+    let collider = match shape {
+        BlockShape::SquareLarge => Collider::rectangle(82.0, 82.0),
+        BlockShape::SquareMedium => Collider::rectangle(41.0, 41.0),
+        BlockShape::SquareSmall => Collider::rectangle(20.0, 20.0),
+        BlockShape::LongBeam => Collider::rectangle(167.0, 20.0),
+        BlockShape::ShortBeam => Collider::rectangle(83.0, 41.0),
+        BlockShape::Triangle => Collider::triangle(
+            Vector::new(0.0, 41.0),
+            Vector::new(-41.0, -41.0),
+            Vector::new(41.0, -41.0),
+        ),
+    };
+
+    let asset_path = if matches!(material, BlockMaterial::Invisible) {
+        "".to_string()
+    } else {
+        format!("blocks/{}_{}.png", material_str, shape_str)
+    };
+    let mut cmd = commands.spawn((
+        Sprite::from_image(asset_server.load(asset_path)),
+        Transform::from_xyz(pos.x, pos.y, 0.0).with_rotation(rotation),
+        if matches!(material, BlockMaterial::Invisible) {
+            RigidBody::Static
+        } else {
+            RigidBody::Dynamic
+        },
+        collider,
+        Block,
+    ));
+
+    if matches!(material, BlockMaterial::Invisible) {
+        cmd.insert(Invisible);
+    }
+    cmd.insert(material);
+
+    if let Some(desc) = description {
+        cmd.insert(BlockDescription(desc));
+    }
+}
+
+pub fn spawn_pig(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    pig_type: PigType,
+    pos: Vec2,
+) {
+    let (path, collider) = match pig_type {
+        PigType::King => ("pigs/pig_king.png", Collider::circle(70.0)),
+        PigType::Normal => ("pigs/pig_normal.png", Collider::circle(23.0)),
+        PigType::RedBird => ("birds/red.png", Collider::circle(22.0)),
+        PigType::BombBird => ("birds/black.png", Collider::circle(42.0)),
+        PigType::TriangleBird => (
+            "birds/yellow.png",
+            Collider::triangle(
+                Vector::new(0.0, 39.0),
+                Vector::new(-39.0, -39.0),
+                Vector::new(39.0, -39.0),
+            ),
+        ),
+        PigType::EggBird => ("birds/white.png", Collider::capsule(40.0, 60.0)), // Approximate capsule
+        PigType::BlueBird => ("birds/blue.png", Collider::circle(22.0)),
+    };
+
+    commands.spawn((
+        Sprite::from_image(asset_server.load(path)),
+        Transform::from_xyz(pos.x, pos.y, 0.0),
+        RigidBody::Dynamic,
+        collider,
+        CollidingEntities::default(),
+        Pig,
+        pig_type,
+    ));
+}
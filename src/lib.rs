@@ -0,0 +1,77 @@
+// Game logic, split into plugins so the game, the command-line tools and tests can each
+// pick the parts they need. `GamePlugins` adds everything the interactive game uses.
+use bevy::{app::PluginGroupBuilder, prelude::*};
+
+pub mod animation;
+pub mod camera;
+pub mod content;
+pub mod debug;
+pub mod dependency;
+pub mod destruction;
+pub mod disaster;
+pub mod encyclopedia;
+pub mod level;
+pub mod locale;
+pub mod menu;
+pub mod particles;
+pub mod quiz;
+pub mod sandbox;
+pub mod shake;
+pub mod sim;
+pub mod slingshot;
+pub mod solver;
+pub mod sound;
+pub mod thumbnail;
+pub mod time_control;
+pub mod trail;
+pub mod ui;
+
+pub use animation::AnimationPlugin;
+pub use camera::CameraPlugin;
+pub use content::ContentPlugin;
+pub use debug::DebugPlugin;
+pub use dependency::DependencyPlugin;
+pub use destruction::DestructionPlugin;
+pub use disaster::DisasterPlugin;
+pub use encyclopedia::EncyclopediaPlugin;
+pub use level::LevelPlugin;
+pub use locale::LocalePlugin;
+pub use menu::MenuPlugin;
+pub use particles::ParticlePlugin;
+pub use quiz::QuizPlugin;
+pub use sandbox::SandboxPlugin;
+pub use shake::ShakePlugin;
+pub use slingshot::SlingshotPlugin;
+pub use sound::SoundPlugin;
+pub use time_control::TimeControlPlugin;
+pub use trail::TrailPlugin;
+pub use ui::UiPlugin;
+
+// Expects DefaultPlugins, avian's PhysicsPlugins and bevy_egui's EguiPlugin to be added first
+pub struct GamePlugins;
+
+impl PluginGroup for GamePlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(ContentPlugin)
+            .add(LocalePlugin)
+            .add(MenuPlugin)
+            .add(LevelPlugin)
+            .add(SlingshotPlugin)
+            .add(DestructionPlugin)
+            .add(DisasterPlugin)
+            .add(DependencyPlugin)
+            .add(SoundPlugin)
+            .add(ParticlePlugin)
+            .add(AnimationPlugin)
+            .add(TrailPlugin)
+            .add(ShakePlugin)
+            .add(CameraPlugin)
+            .add(UiPlugin)
+            .add(EncyclopediaPlugin)
+            .add(QuizPlugin)
+            .add(SandboxPlugin)
+            .add(TimeControlPlugin)
+            .add(DebugPlugin)
+    }
+}
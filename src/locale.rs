@@ -0,0 +1,178 @@
+// Translations: UI strings from `assets/locale/<code>.strings.json` and the educational
+// content from each language's content pack, switchable at runtime. Anything missing in
+// the chosen language falls back to English.
+use std::collections::HashMap;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use serde::Deserialize;
+
+use crate::content::{ContentEntry, ContentPack, JsonLoader};
+
+pub struct LocalePlugin;
+
+impl Plugin for LocalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<StringTable>()
+            .register_asset_loader(JsonLoader::<StringTable>::new(&["strings.json"]))
+            .init_resource::<CurrentLanguage>()
+            .add_systems(PreStartup, load_locales);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: &[Language] = &[Language::English, Language::Spanish];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+
+    // Shown in the language picker, always in the language itself
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+        }
+    }
+
+    fn strings_path(self) -> String {
+        format!("locale/{}.strings.json", self.code())
+    }
+
+    // English is the original pack, the others sit in a folder named by language code
+    fn content_path(self) -> String {
+        match self {
+            Language::English => "content/open_source.content.json".to_string(),
+            _ => format!("content/{}/open_source.content.json", self.code()),
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct CurrentLanguage(pub Language);
+
+// Translated UI strings by key. `{name}` in a string is filled in by `Localizer::tr_args`.
+#[derive(Asset, TypePath, Deserialize)]
+#[serde(transparent)]
+pub struct StringTable(HashMap<String, String>);
+
+// Handles keeping every language loaded, so switching is instant
+#[derive(Resource)]
+pub struct LocaleAssets {
+    strings: HashMap<Language, Handle<StringTable>>,
+    content: HashMap<Language, Handle<ContentPack>>,
+}
+
+fn load_locales(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(LocaleAssets {
+        strings: load_all(&asset_server, Language::strings_path),
+        content: load_all(&asset_server, Language::content_path),
+    });
+}
+
+fn load_all<A: Asset>(
+    asset_server: &AssetServer,
+    path: fn(Language) -> String,
+) -> HashMap<Language, Handle<A>> {
+    Language::ALL
+        .iter()
+        .map(|&language| (language, asset_server.load(path(language))))
+        .collect()
+}
+
+// Looks up text in the current language, then English. Until the files have loaded UI
+// strings show as their keys and content isn't found.
+#[derive(SystemParam)]
+pub struct Localizer<'w> {
+    language: Res<'w, CurrentLanguage>,
+    locales: Res<'w, LocaleAssets>,
+    strings: Res<'w, Assets<StringTable>>,
+    packs: Res<'w, Assets<ContentPack>>,
+}
+
+impl Localizer<'_> {
+    pub fn language(&self) -> Language {
+        self.language.0
+    }
+
+    pub fn tr(&self, key: &str) -> String {
+        self.fallback_chain()
+            .find_map(|language| {
+                let table = self.strings.get(self.locales.strings.get(&language)?)?;
+                table.0.get(key).cloned()
+            })
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    pub fn tr_args(&self, key: &str, args: &[(&str, &dyn std::fmt::Display)]) -> String {
+        args.iter().fold(self.tr(key), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), &value.to_string())
+        })
+    }
+
+    pub fn content(&self, id: &str) -> Option<&ContentEntry> {
+        self.fallback_chain().find_map(|language| {
+            let pack = self.packs.get(self.locales.content.get(&language)?)?;
+            pack.get(id)
+        })
+    }
+
+    fn fallback_chain(&self) -> impl Iterator<Item = Language> {
+        [self.language.0, Language::English].into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translations_only_use_english_keys_and_ids() {
+        let english_strings: StringTable =
+            serde_json::from_slice(include_bytes!("../assets/locale/en.strings.json")).unwrap();
+        let english_content =
+            ContentPack::from_json(include_bytes!("../assets/content/open_source.content.json"))
+                .unwrap();
+        let translations: &[(&[u8], &[u8])] = &[(
+            include_bytes!("../assets/locale/es.strings.json"),
+            include_bytes!("../assets/content/es/open_source.content.json"),
+        )];
+
+        for (strings, content) in translations {
+            let strings: StringTable = serde_json::from_slice(strings).unwrap();
+            for key in strings.0.keys() {
+                assert!(english_strings.0.contains_key(key), "unknown key {key}");
+            }
+            for entry in ContentPack::from_json(content).unwrap().entries {
+                assert!(
+                    english_content.get(&entry.id).is_some(),
+                    "unknown content id {}",
+                    entry.id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_level_name_has_an_english_string() {
+        let english_strings: StringTable =
+            serde_json::from_slice(include_bytes!("../assets/locale/en.strings.json")).unwrap();
+        for level in crate::level::LEVELS {
+            assert!(
+                english_strings.0.contains_key(level.name_key),
+                "level {} has no string {}",
+                level.id,
+                level.name_key
+            );
+        }
+    }
+}
@@ -0,0 +1,369 @@
+// Menus and the game-state machine behind them: the main menu, level select, settings and
+// the Esc pause menu. Gameplay systems only run while the game is Playing, and physics is
+// held still whenever a menu is up.
+use avian2d::prelude::*;
+use bevy::{
+    audio::{GlobalVolume, Volume},
+    prelude::*,
+    window::{MonitorSelection, PresentMode, PrimaryWindow, WindowMode},
+};
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use crate::{
+    encyclopedia::EncyclopediaPanel,
+    level::{Block, CurrentLevel, LEVELS, Pig, spawn_level},
+    locale::{CurrentLanguage, Language, Localizer},
+    quiz::QuizSettings,
+    sandbox::Sandbox,
+    shake::ShakeSettings,
+    slingshot::{AimMode, Bird, RespawnTimer, SlingshotState, load_slingshot, spawn_bird},
+    sound::{AudioSettings, MUTE_KEY},
+    time_control::RewindBuffer,
+};
+
+pub const MENU_KEY: KeyCode = KeyCode::Escape;
+const MENU_WIDTH: f32 = 260.0;
+
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<GameState>()
+            .add_message::<LoadLevel>()
+            .init_resource::<SettingsReturn>()
+            .init_resource::<MenuHeldPhysics>()
+            .add_systems(OnEnter(GameState::MainMenu), hold_physics)
+            .add_systems(OnExit(GameState::Playing), hold_physics)
+            .add_systems(OnEnter(GameState::Playing), release_physics)
+            .add_systems(Update, (menu_key_system, load_level_system))
+            .add_systems(
+                EguiPrimaryContextPass,
+                (
+                    main_menu_system.run_if(in_state(GameState::MainMenu)),
+                    level_select_system.run_if(in_state(GameState::LevelSelect)),
+                    settings_menu_system.run_if(in_state(GameState::Settings)),
+                    pause_menu_system.run_if(in_state(GameState::Paused)),
+                ),
+            );
+    }
+}
+
+#[derive(States, Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GameState {
+    #[default]
+    MainMenu,
+    LevelSelect,
+    Settings,
+    Playing,
+    Paused,
+}
+
+// Despawns everything on the level and sets it up again from the start, with the score,
+// slingshot and rewind history reset. Restart sends the current level.
+#[derive(Message)]
+pub struct LoadLevel(pub usize);
+
+// Where Back goes from the settings screen, which opens from the main and pause menus
+#[derive(Resource)]
+struct SettingsReturn(GameState);
+
+impl Default for SettingsReturn {
+    fn default() -> Self {
+        SettingsReturn(GameState::MainMenu)
+    }
+}
+
+// Whether a menu paused physics, as opposed to the player with the time controls, so
+// leaving the menu only unpauses what it paused
+#[derive(Resource, Default)]
+struct MenuHeldPhysics(bool);
+
+fn hold_physics(mut time: ResMut<Time<Physics>>, mut held: ResMut<MenuHeldPhysics>) {
+    if !time.is_paused() {
+        time.pause();
+        held.0 = true;
+    }
+}
+
+fn release_physics(mut time: ResMut<Time<Physics>>, mut held: ResMut<MenuHeldPhysics>) {
+    if held.0 {
+        time.unpause();
+        held.0 = false;
+    }
+}
+
+// Esc pauses and resumes, and steps back out of the other menus
+fn menu_key_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    settings_return: Res<SettingsReturn>,
+) {
+    if !keyboard.just_pressed(MENU_KEY) {
+        return;
+    }
+    next_state.set(match state.get() {
+        GameState::MainMenu => return,
+        GameState::LevelSelect => GameState::MainMenu,
+        GameState::Settings => settings_return.0,
+        GameState::Playing => GameState::Paused,
+        GameState::Paused => GameState::Playing,
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn load_level_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut requests: MessageReader<LoadLevel>,
+    query: Query<Entity, Or<(With<Block>, With<Pig>, With<Bird>)>>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut slingshot_state: ResMut<SlingshotState>,
+    mut respawn_timer: ResMut<RespawnTimer>,
+    mut rewind: ResMut<RewindBuffer>,
+) {
+    let Some(&LoadLevel(index)) = requests.read().last() else {
+        return;
+    };
+
+    // Despawn all game entities
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    // Reset state
+    current_level.0 = index;
+    load_slingshot(&mut slingshot_state, &LEVELS[index]);
+    respawn_timer.0.reset();
+    rewind.clear();
+
+    // Respawn level
+    spawn_level(&mut commands, &asset_server, &LEVELS[index]);
+    spawn_bird(&mut commands, &asset_server);
+}
+
+// A fixed-width window in the middle of the screen
+fn menu_window(title: String, id: &str) -> egui::Window<'static> {
+    egui::Window::new(title)
+        .id(egui::Id::new(id))
+        .anchor(egui::Align2::CENTER_CENTER, (0.0, 0.0))
+        .collapsible(false)
+        .resizable(false)
+        .default_width(MENU_WIDTH)
+}
+
+fn volume_slider(value: &mut f32, text: String) -> egui::Slider<'_> {
+    egui::Slider::new(value, 0.0..=1.0)
+        .text(text)
+        .custom_formatter(|value, _| format!("{:.0}%", value * 100.0))
+}
+
+fn menu_button(ui: &mut egui::Ui, text: String) -> bool {
+    ui.add_sized([MENU_WIDTH, 0.0], egui::Button::new(text))
+        .clicked()
+}
+
+fn main_menu_system(
+    mut contexts: EguiContexts,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut settings_return: ResMut<SettingsReturn>,
+    mut sandbox: ResMut<Sandbox>,
+    mut encyclopedia_panel: ResMut<EncyclopediaPanel>,
+    mut exit: MessageWriter<AppExit>,
+    locale: Localizer,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    menu_window(locale.tr("menu.title"), "main_menu").show(ctx, |ui| {
+        ui.vertical_centered(|ui| {
+            if menu_button(ui, locale.tr("menu.play")) {
+                sandbox.enabled = false;
+                next_state.set(GameState::Playing);
+            }
+            if menu_button(ui, locale.tr("menu.level-select")) {
+                next_state.set(GameState::LevelSelect);
+            }
+            if menu_button(ui, locale.tr("menu.sandbox")) {
+                sandbox.enabled = true;
+                next_state.set(GameState::Playing);
+            }
+            if menu_button(ui, locale.tr("menu.encyclopedia")) {
+                encyclopedia_panel.open = !encyclopedia_panel.open;
+            }
+            if menu_button(ui, locale.tr("menu.settings")) {
+                settings_return.0 = GameState::MainMenu;
+                next_state.set(GameState::Settings);
+            }
+            // Closing the tab is how a browser game quits
+            if cfg!(not(target_arch = "wasm32")) && menu_button(ui, locale.tr("menu.quit")) {
+                exit.write(AppExit::Success);
+            }
+        });
+    });
+}
+
+fn level_select_system(
+    mut contexts: EguiContexts,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut load_level: MessageWriter<LoadLevel>,
+    mut sandbox: ResMut<Sandbox>,
+    current_level: Res<CurrentLevel>,
+    locale: Localizer,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    menu_window(locale.tr("level-select.title"), "level_select").show(ctx, |ui| {
+        ui.vertical_centered(|ui| {
+            for (index, level) in LEVELS.iter().enumerate() {
+                let text = format!("{}. {}", index + 1, locale.tr(level.name_key));
+                let button = egui::Button::selectable(index == current_level.0, text);
+                if ui.add_sized([MENU_WIDTH, 0.0], button).clicked() {
+                    load_level.write(LoadLevel(index));
+                    sandbox.enabled = false;
+                    next_state.set(GameState::Playing);
+                }
+            }
+            ui.separator();
+            if menu_button(ui, locale.tr("menu.back")) {
+                next_state.set(GameState::MainMenu);
+            }
+        });
+    });
+}
+
+fn pause_menu_system(
+    mut contexts: EguiContexts,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut settings_return: ResMut<SettingsReturn>,
+    mut load_level: MessageWriter<LoadLevel>,
+    current_level: Res<CurrentLevel>,
+    locale: Localizer,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    menu_window(locale.tr("pause.title"), "pause_menu").show(ctx, |ui| {
+        ui.vertical_centered(|ui| {
+            if menu_button(ui, locale.tr("pause.resume")) {
+                next_state.set(GameState::Playing);
+            }
+            if menu_button(ui, locale.tr("pause.restart")) {
+                load_level.write(LoadLevel(current_level.0));
+                next_state.set(GameState::Playing);
+            }
+            if menu_button(ui, locale.tr("menu.settings")) {
+                settings_return.0 = GameState::Paused;
+                next_state.set(GameState::Settings);
+            }
+            // Play from the main menu starts the level afresh
+            if menu_button(ui, locale.tr("pause.quit-to-menu")) {
+                load_level.write(LoadLevel(current_level.0));
+                next_state.set(GameState::MainMenu);
+            }
+        });
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn settings_menu_system(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    mut next_state: ResMut<NextState<GameState>>,
+    settings_return: Res<SettingsReturn>,
+    mut volume: ResMut<GlobalVolume>,
+    mut audio: ResMut<AudioSettings>,
+    mut aim_mode: ResMut<AimMode>,
+    mut quiz_settings: ResMut<QuizSettings>,
+    mut shake: ResMut<ShakeSettings>,
+    mut window_q: Query<&mut Window, With<PrimaryWindow>>,
+    locale: Localizer,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    // `locale` is reading the current language, so a new pick is applied by command
+    let mut picked = locale.language();
+    menu_window(locale.tr("settings.title"), "settings").show(ctx, |ui| {
+        ui.label(locale.tr("settings.volume"));
+        let mut master = volume.volume.to_linear();
+        if ui
+            .add(volume_slider(&mut master, locale.tr("settings.master")))
+            .changed()
+        {
+            volume.volume = Volume::Linear(master);
+        }
+        ui.add(volume_slider(&mut audio.music, locale.tr("settings.music")));
+        ui.add(volume_slider(
+            &mut audio.effects,
+            locale.tr("settings.effects"),
+        ));
+        let mute_label = locale.tr_args("settings.mute", &[("key", &format!("{MUTE_KEY:?}"))]);
+        ui.checkbox(&mut audio.muted, mute_label);
+        ui.separator();
+        ui.label(locale.tr("settings.language"));
+        for &option in Language::ALL {
+            ui.radio_value(&mut picked, option, option.native_name());
+        }
+        ui.separator();
+        ui.label(locale.tr("settings.aim-with"));
+        ui.radio_value(
+            &mut *aim_mode,
+            AimMode::Drag,
+            locale.tr("settings.aim-drag"),
+        );
+        ui.radio_value(
+            &mut *aim_mode,
+            AimMode::Buttons,
+            locale.tr("settings.aim-buttons"),
+        );
+        ui.separator();
+        if let Ok(mut window) = window_q.single_mut() {
+            ui.label(locale.tr("settings.graphics"));
+            let mut fullscreen = window.mode != WindowMode::Windowed;
+            if ui
+                .checkbox(&mut fullscreen, locale.tr("settings.fullscreen"))
+                .changed()
+            {
+                window.mode = if fullscreen {
+                    WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+                } else {
+                    WindowMode::Windowed
+                };
+            }
+            let mut vsync = window.present_mode != PresentMode::AutoNoVsync;
+            if ui
+                .checkbox(&mut vsync, locale.tr("settings.vsync"))
+                .changed()
+            {
+                window.present_mode = if vsync {
+                    PresentMode::AutoVsync
+                } else {
+                    PresentMode::AutoNoVsync
+                };
+            }
+            ui.separator();
+        }
+        ui.label(locale.tr("settings.motion"));
+        ui.checkbox(&mut shake.shake, locale.tr("settings.shake"));
+        ui.add_enabled(
+            shake.shake,
+            egui::Slider::new(&mut shake.intensity, 0.0..=2.0)
+                .text(locale.tr("settings.shake-intensity"))
+                .custom_formatter(|value, _| format!("{:.0}%", value * 100.0)),
+        );
+        ui.checkbox(&mut shake.hit_stop, locale.tr("settings.hit-stop"));
+        ui.separator();
+        ui.checkbox(&mut quiz_settings.enabled, locale.tr("settings.quiz"));
+        ui.separator();
+        ui.vertical_centered(|ui| {
+            if menu_button(ui, locale.tr("menu.back")) {
+                next_state.set(settings_return.0);
+            }
+        });
+    });
+    if picked != locale.language() {
+        commands.insert_resource(CurrentLanguage(picked));
+    }
+}
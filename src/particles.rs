@@ -0,0 +1,290 @@
+// Particles: splinters and sparks when blocks are hit, weakened or broken, dust when
+// bodies hit the ground, and feathers or smoke when a pig is defeated. Plain sprites
+// moved on the CPU, so they work the same on the WebGL2 build.
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    destruction::{BlockBroken, HEAVY_IMPULSE, Impact, PigDefeated, Surface, Weakened},
+    level::{BlockMaterial, PigType},
+};
+
+// Particles past this many are dropped, so a collapse can't flood the world with sprites
+const MAX_PARTICLES: usize = 400;
+// In front of blocks and birds
+const PARTICLE_Z: f32 = 5.0;
+
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<Impact>()
+            .add_message::<BlockBroken>()
+            .add_message::<PigDefeated>()
+            .add_systems(
+                Update,
+                (
+                    impact_particles_system,
+                    weakened_particles_system,
+                    destruction_particles_system,
+                    update_particles_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+// How one kind of particle is thrown out and behaves
+struct Burst {
+    count: usize,
+    colors: &'static [Color],
+    // Ranges, picked from at random per particle
+    size: (f32, f32),
+    speed: (f32, f32),
+    lifetime: (f32, f32),
+    // Direction particles fly in, radians either side of straight up
+    spread: f32,
+    gravity: f32,
+    // Fraction of speed lost per second
+    drag: f32,
+    spin: f32,
+    // Pixels of growth per second, for smoke and dust that billow out
+    growth: f32,
+}
+
+const SPLINTERS: Burst = Burst {
+    count: 10,
+    colors: &[Color::srgb(0.6, 0.4, 0.2), Color::srgb(0.8, 0.6, 0.35)],
+    size: (3.0, 7.0),
+    speed: (150.0, 400.0),
+    lifetime: (0.5, 1.0),
+    spread: TAU,
+    gravity: 900.0,
+    drag: 0.5,
+    spin: 15.0,
+    growth: 0.0,
+};
+
+const SPARKS: Burst = Burst {
+    count: 10,
+    colors: &[Color::srgb(1.0, 0.9, 0.5), Color::srgb(1.0, 0.6, 0.2)],
+    size: (2.0, 4.0),
+    speed: (250.0, 600.0),
+    lifetime: (0.2, 0.5),
+    spread: TAU,
+    gravity: 600.0,
+    drag: 2.0,
+    spin: 0.0,
+    growth: 0.0,
+};
+
+const DUST: Burst = Burst {
+    count: 8,
+    colors: &[Color::srgb(0.6, 0.55, 0.45), Color::srgb(0.7, 0.65, 0.55)],
+    size: (8.0, 14.0),
+    speed: (40.0, 150.0),
+    lifetime: (0.5, 0.9),
+    spread: 1.3,
+    gravity: -20.0,
+    drag: 3.0,
+    spin: 2.0,
+    growth: 20.0,
+};
+
+const SMOKE: Burst = Burst {
+    count: 14,
+    colors: &[Color::srgb(0.85, 0.85, 0.85), Color::srgb(0.65, 0.75, 0.6)],
+    size: (12.0, 20.0),
+    speed: (40.0, 160.0),
+    lifetime: (0.7, 1.3),
+    spread: TAU,
+    gravity: -60.0,
+    drag: 2.0,
+    spin: 1.0,
+    growth: 30.0,
+};
+
+// Floats down slowly, with the colours swapped in per bird
+const FEATHERS: Burst = Burst {
+    count: 12,
+    colors: &[],
+    size: (5.0, 9.0),
+    speed: (80.0, 250.0),
+    lifetime: (1.0, 1.8),
+    spread: TAU,
+    gravity: 120.0,
+    drag: 2.5,
+    spin: 6.0,
+    growth: 0.0,
+};
+
+#[derive(Component)]
+struct Particle {
+    velocity: Vec2,
+    gravity: f32,
+    drag: f32,
+    spin: f32,
+    growth: f32,
+    age: f32,
+    lifetime: f32,
+    color: Color,
+}
+
+fn spawn_burst(
+    commands: &mut Commands,
+    burst: &Burst,
+    colors: &[Color],
+    position: Vec2,
+    count: usize,
+) {
+    let mut rng = rand::rng();
+    for _ in 0..count {
+        let angle = FRAC_PI_2 + rng.random_range(-burst.spread / 2.0..=burst.spread / 2.0);
+        let speed = rng.random_range(burst.speed.0..=burst.speed.1);
+        let size = rng.random_range(burst.size.0..=burst.size.1);
+        let color = colors[rng.random_range(0..colors.len())];
+        commands.spawn((
+            Sprite::from_color(color, Vec2::splat(size)),
+            Transform::from_translation(position.extend(PARTICLE_Z))
+                .with_rotation(Quat::from_rotation_z(rng.random_range(0.0..TAU))),
+            Particle {
+                velocity: Vec2::from_angle(angle) * speed,
+                gravity: burst.gravity,
+                drag: burst.drag,
+                spin: rng.random_range(-burst.spin..=burst.spin),
+                growth: burst.growth,
+                age: 0.0,
+                lifetime: rng.random_range(burst.lifetime.0..=burst.lifetime.1),
+                color,
+            },
+        ));
+    }
+}
+
+// A hit's splinters, sparks or dust, fewer for lighter hits
+fn impact_particles_system(mut commands: Commands, mut impacts: MessageReader<Impact>) {
+    for impact in impacts.read() {
+        let strength = (impact.impulse / HEAVY_IMPULSE).clamp(0.2, 1.0);
+        for surface in impact.surfaces {
+            let burst = match surface {
+                Surface::Wood => &SPLINTERS,
+                Surface::Steel => &SPARKS,
+                Surface::Ground => &DUST,
+                Surface::Creature => continue,
+            };
+            let count = (burst.count as f32 * strength / 2.0).ceil() as usize;
+            spawn_burst(&mut commands, burst, burst.colors, impact.position, count);
+        }
+    }
+}
+
+// A block damaged without breaking
+fn weakened_particles_system(
+    mut commands: Commands,
+    block_q: Query<(&Transform, &BlockMaterial), Added<Weakened>>,
+) {
+    for (transform, material) in block_q.iter() {
+        let burst = material_burst(*material);
+        let position = transform.translation.truncate();
+        spawn_burst(
+            &mut commands,
+            burst,
+            burst.colors,
+            position,
+            burst.count / 2,
+        );
+    }
+}
+
+fn destruction_particles_system(
+    mut commands: Commands,
+    mut broken: MessageReader<BlockBroken>,
+    mut defeated: MessageReader<PigDefeated>,
+) {
+    for block in broken.read() {
+        let burst = material_burst(block.material);
+        spawn_burst(
+            &mut commands,
+            burst,
+            burst.colors,
+            block.position,
+            burst.count * 2,
+        );
+    }
+    for pig in defeated.read() {
+        match feather_colors(pig.pig_type) {
+            Some(colors) => spawn_burst(
+                &mut commands,
+                &FEATHERS,
+                colors,
+                pig.position,
+                FEATHERS.count,
+            ),
+            None => spawn_burst(
+                &mut commands,
+                &SMOKE,
+                SMOKE.colors,
+                pig.position,
+                SMOKE.count,
+            ),
+        }
+    }
+}
+
+fn material_burst(material: BlockMaterial) -> &'static Burst {
+    match material {
+        BlockMaterial::Steel => &SPARKS,
+        _ => &SPLINTERS,
+    }
+}
+
+// The birds lose feathers in their own colours, pigs go up in smoke
+fn feather_colors(pig_type: PigType) -> Option<&'static [Color]> {
+    let colors: &'static [Color] = match pig_type {
+        PigType::King | PigType::Normal => return None,
+        PigType::RedBird => &[Color::srgb(0.85, 0.15, 0.15), Color::srgb(0.95, 0.9, 0.8)],
+        PigType::BombBird => &[Color::srgb(0.15, 0.15, 0.15), Color::srgb(0.35, 0.35, 0.35)],
+        PigType::TriangleBird => &[Color::srgb(0.95, 0.85, 0.2), Color::srgb(1.0, 0.95, 0.7)],
+        PigType::EggBird => &[Color::srgb(0.95, 0.95, 0.9), Color::srgb(0.85, 0.85, 0.8)],
+        PigType::BlueBird => &[Color::srgb(0.35, 0.6, 0.95), Color::srgb(0.8, 0.9, 1.0)],
+    };
+    Some(colors)
+}
+
+// Particles keep to physics time, so they freeze when it's paused and slow down with it
+fn update_particles_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    physics_time: Res<Time<Physics>>,
+    mut particle_q: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+) {
+    let dt = if physics_time.is_paused() {
+        0.0
+    } else {
+        time.delta_secs() * physics_time.relative_speed()
+    };
+
+    let mut live = particle_q.iter().count();
+    for (entity, mut particle, mut transform, mut sprite) in particle_q.iter_mut() {
+        let particle = &mut *particle;
+        particle.age += dt;
+        if particle.age >= particle.lifetime || live > MAX_PARTICLES {
+            commands.entity(entity).despawn();
+            live -= 1;
+            continue;
+        }
+        particle.velocity.y -= particle.gravity * dt;
+        particle.velocity *= (1.0 - particle.drag * dt).max(0.0);
+        transform.translation += (particle.velocity * dt).extend(0.0);
+        transform.rotate_z(particle.spin * dt);
+        if let Some(size) = &mut sprite.custom_size {
+            *size += Vec2::splat(particle.growth * dt);
+        }
+        sprite.color = particle
+            .color
+            .with_alpha(1.0 - particle.age / particle.lifetime);
+    }
+}
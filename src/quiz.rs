@@ -0,0 +1,155 @@
+// Post-shot quiz: a little after a pig is launched, ask a multiple-choice question about
+// the disaster it stood for. Right answers earn points; questions come from the content
+// pack and aren't asked again once answered correctly.
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use crate::{
+    level::Score,
+    locale::Localizer,
+    menu::GameState,
+    slingshot::{Bird, OnSlingshot, SlingshotState},
+};
+
+// Long enough to watch the pig land first
+const QUIZ_DELAY_SECONDS: f32 = 3.0;
+pub const QUIZ_POINTS: u32 = 500;
+
+pub struct QuizPlugin;
+
+impl Plugin for QuizPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<QuizSettings>()
+            .init_resource::<QuizState>()
+            .add_systems(
+                Update,
+                queue_quiz_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                quiz_ui_system.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+#[derive(Resource)]
+pub struct QuizSettings {
+    pub enabled: bool,
+}
+
+impl Default for QuizSettings {
+    fn default() -> Self {
+        QuizSettings { enabled: true }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct QuizState {
+    // Disaster just launched, asked about once the timer runs out
+    pending: Option<(&'static str, Timer)>,
+    asking: Option<Question>,
+    // Disasters already answered correctly
+    mastered: Vec<&'static str>,
+}
+
+#[derive(Clone, Copy)]
+struct Question {
+    id: &'static str,
+    picked: Option<usize>,
+}
+
+fn queue_quiz_system(
+    time: Res<Time>,
+    settings: Res<QuizSettings>,
+    slingshot: Res<SlingshotState>,
+    mut quiz: ResMut<QuizState>,
+    mut launched: RemovedComponents<OnSlingshot>,
+    bird_q: Query<(), With<Bird>>,
+) {
+    for entity in launched.read() {
+        if settings.enabled
+            && quiz.asking.is_none()
+            && bird_q.contains(entity)
+            && let Some(disaster) = slingshot.disaster
+            && !quiz.mastered.contains(&disaster.content)
+        {
+            let timer = Timer::from_seconds(QUIZ_DELAY_SECONDS, TimerMode::Once);
+            quiz.pending = Some((disaster.content, timer));
+        }
+    }
+
+    let quiz = &mut *quiz;
+    if let Some((id, timer)) = &mut quiz.pending
+        && timer.tick(time.delta()).just_finished()
+    {
+        quiz.asking = Some(Question {
+            id: *id,
+            picked: None,
+        });
+        quiz.pending = None;
+    }
+}
+
+pub fn quiz_ui_system(
+    mut contexts: EguiContexts,
+    mut quiz: ResMut<QuizState>,
+    mut score: ResMut<Score>,
+    locale: Localizer,
+) {
+    let Some(Question { id, picked }) = quiz.asking else {
+        return;
+    };
+    let Some(question) = locale.content(id).and_then(|entry| entry.quiz.as_ref()) else {
+        // Nothing to ask about this disaster
+        quiz.asking = None;
+        return;
+    };
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let mut new_pick = None;
+    let mut done = false;
+    egui::Window::new(locale.tr("quiz.title"))
+        .id(egui::Id::new("quiz"))
+        .anchor(egui::Align2::CENTER_CENTER, (0.0, 0.0))
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.strong(&question.question);
+            for (index, answer) in question.answers.iter().enumerate() {
+                if ui
+                    .add_enabled(picked.is_none(), egui::Button::new(answer))
+                    .clicked()
+                {
+                    new_pick = Some(index);
+                }
+            }
+
+            if let Some(picked) = picked {
+                ui.separator();
+                if picked == question.correct {
+                    ui.label(locale.tr_args("quiz.correct", &[("points", &QUIZ_POINTS)]));
+                } else {
+                    let answer = &question.answers[question.correct];
+                    ui.label(locale.tr_args("quiz.wrong", &[("answer", answer)]));
+                }
+                ui.label(locale.tr_args("quiz.score", &[("score", &score.0)]));
+                done = ui.button(locale.tr("quiz.continue")).clicked();
+            }
+        });
+
+    if let Some(new_pick) = new_pick {
+        if new_pick == question.correct {
+            score.0 += QUIZ_POINTS;
+            quiz.mastered.push(id);
+        }
+        quiz.asking = Some(Question {
+            id,
+            picked: Some(new_pick),
+        });
+    }
+    if done {
+        quiz.asking = None;
+    }
+}
@@ -0,0 +1,241 @@
+// Sandbox mode for experimenting with structures: pick up any loose block or pig, drag it
+// around on a spring and let go to throw it, or click empty space to spawn a new block or
+// pig from the palette. The spring is a physics joint, so it pauses and slows down with
+// physics time.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui, input::EguiWantsInput};
+
+use crate::{
+    level::{Block, BlockMaterial, BlockShape, Pig, PigType, spawn_block, spawn_pig},
+    locale::Localizer,
+    menu::GameState,
+    slingshot::Pointer,
+};
+
+// Softness of the spring to the pointer (stretch per unit of force), tuned so a medium
+// wooden block follows briskly, and how much it resists motion; a little under critically
+// damped so a held body swings slightly
+const SPRING_COMPLIANCE: f32 = 4.0e-6;
+const SPRING_DAMPING: f32 = 20.0;
+const SPRING_COLOR: Color = Color::srgb(1.0, 0.6, 0.2);
+
+// Shapes offered in the palette, with their string keys
+const PALETTE_SHAPES: &[(BlockShape, &str)] = &[
+    (BlockShape::SquareLarge, "sandbox.square-large"),
+    (BlockShape::SquareMedium, "sandbox.square-medium"),
+    (BlockShape::SquareSmall, "sandbox.square-small"),
+    (BlockShape::LongBeam, "sandbox.beam-long"),
+    (BlockShape::ShortBeam, "sandbox.beam-short"),
+    (BlockShape::Triangle, "sandbox.triangle"),
+];
+
+pub struct SandboxPlugin;
+
+impl Plugin for SandboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Sandbox>()
+            .add_systems(
+                Update,
+                sandbox_input_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                sandbox_ui_system.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+// What a click on empty space spawns
+#[derive(Clone, Copy, PartialEq)]
+pub enum PaletteItem {
+    Block(BlockMaterial, BlockShape),
+    Pig,
+}
+
+#[derive(Resource, Default)]
+pub struct Sandbox {
+    pub enabled: bool,
+    pub palette: Option<PaletteItem>,
+    held: Option<Held>,
+}
+
+// The body being dragged, and where on it it was picked up (relative to its centre,
+// unrotated) so it hangs from that point. It's joined by a spring to `anchor`, which
+// follows the pointer.
+#[derive(Clone, Copy)]
+struct Held {
+    entity: Entity,
+    local_grab: Vec2,
+    anchor: Entity,
+    joint: Entity,
+}
+
+#[derive(Component)]
+struct GrabAnchor;
+
+// Starts dragging `entity` by the point under `world_pos`
+fn grab(commands: &mut Commands, entity: Entity, local_grab: Vec2, world_pos: Vec2) -> Held {
+    let anchor = commands
+        .spawn((RigidBody::Kinematic, Position(world_pos), GrabAnchor))
+        .id();
+    let joint = commands
+        .spawn((
+            DistanceJoint::new(anchor, entity)
+                .with_local_anchor2(local_grab)
+                .with_limits(0.0, 0.0)
+                .with_compliance(SPRING_COMPLIANCE),
+            JointDamping {
+                linear: SPRING_DAMPING,
+                angular: 0.0,
+            },
+        ))
+        .id();
+    Held {
+        entity,
+        local_grab,
+        anchor,
+        joint,
+    }
+}
+
+// Lets go of the held body, which keeps whatever speed the spring gave it
+fn let_go(commands: &mut Commands, sandbox: &mut Sandbox) {
+    if let Some(held) = sandbox.held.take() {
+        commands.entity(held.joint).try_despawn();
+        commands.entity(held.anchor).try_despawn();
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn sandbox_input_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut sandbox: ResMut<Sandbox>,
+    mut gizmos: Gizmos,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    egui_input: Res<EguiWantsInput>,
+    spatial_query: SpatialQuery,
+    grabbable_q: Query<&RigidBody, Or<(With<Block>, With<Pig>)>>,
+    body_q: Query<(&Position, &Rotation), Without<GrabAnchor>>,
+    mut anchor_q: Query<&mut Position, With<GrabAnchor>>,
+) {
+    if !sandbox.enabled {
+        let_go(&mut commands, &mut sandbox);
+        return;
+    }
+    let Some((camera, camera_transform)) = camera_q.iter().next() else {
+        return;
+    };
+    let Some(window) = windows.iter().next() else {
+        return;
+    };
+    let Some((pointer, world_pos)) =
+        Pointer::from_input(window, &mouse_button, &touches).and_then(|pointer| {
+            let world_pos = camera.viewport_to_world_2d(camera_transform, pointer.pos);
+            world_pos.ok().map(|world_pos| (pointer, world_pos))
+        })
+    else {
+        // Lost the pointer, so let go of whatever was held
+        let_go(&mut commands, &mut sandbox);
+        return;
+    };
+
+    if pointer.just_pressed && !egui_input.wants_any_pointer_input() {
+        let under_pointer =
+            spatial_query.point_intersections(world_pos, &SpatialQueryFilter::default());
+        let hit = under_pointer
+            .iter()
+            .copied()
+            .find(|&entity| grabbable_q.get(entity).is_ok_and(|body| body.is_dynamic()));
+        // Only spawn into empty space, not inside the ground or onto the waiting bird
+        let palette = sandbox.palette.filter(|_| under_pointer.is_empty());
+        match (hit, palette) {
+            (Some(entity), _) => {
+                if let Ok((position, rotation)) = body_q.get(entity) {
+                    let local_grab = rotation.inverse() * (world_pos - position.0);
+                    let_go(&mut commands, &mut sandbox);
+                    sandbox.held = Some(grab(&mut commands, entity, local_grab, world_pos));
+                }
+            }
+            (None, Some(PaletteItem::Block(material, shape))) => spawn_block(
+                &mut commands,
+                &asset_server,
+                material,
+                shape,
+                world_pos,
+                Quat::IDENTITY,
+                None,
+            ),
+            (None, Some(PaletteItem::Pig)) => {
+                spawn_pig(&mut commands, &asset_server, PigType::Normal, world_pos)
+            }
+            (None, None) => {}
+        }
+    }
+
+    let Some(held) = sandbox.held else {
+        return;
+    };
+    // Letting go throws the body
+    if !pointer.pressed {
+        let_go(&mut commands, &mut sandbox);
+        return;
+    }
+    let Ok((position, rotation)) = body_q.get(held.entity) else {
+        // Destroyed while held
+        let_go(&mut commands, &mut sandbox);
+        return;
+    };
+    if let Ok(mut anchor) = anchor_q.get_mut(held.anchor) {
+        anchor.0 = world_pos;
+    }
+    let grab_point = position.0 + *rotation * held.local_grab;
+    gizmos.line_2d(grab_point, world_pos, SPRING_COLOR);
+}
+
+pub fn sandbox_ui_system(
+    mut contexts: EguiContexts,
+    mut sandbox: ResMut<Sandbox>,
+    locale: Localizer,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    egui::Window::new(locale.tr("sandbox.title"))
+        .id(egui::Id::new("sandbox"))
+        .default_pos((0.0, 400.0))
+        .default_open(false)
+        .show(ctx, |ui| {
+            ui.checkbox(&mut sandbox.enabled, locale.tr("sandbox.enable"));
+            if !sandbox.enabled {
+                return;
+            }
+            ui.separator();
+            ui.label(locale.tr("sandbox.spawn"));
+            ui.selectable_value(&mut sandbox.palette, None, locale.tr("sandbox.nothing"));
+            for (material, name) in [
+                (BlockMaterial::Wood, "sandbox.wood"),
+                (BlockMaterial::Steel, "sandbox.steel"),
+            ] {
+                ui.label(locale.tr(name));
+                ui.horizontal_wrapped(|ui| {
+                    for &(shape, shape_name) in PALETTE_SHAPES {
+                        ui.selectable_value(
+                            &mut sandbox.palette,
+                            Some(PaletteItem::Block(material, shape)),
+                            locale.tr(shape_name),
+                        );
+                    }
+                });
+            }
+            ui.selectable_value(
+                &mut sandbox.palette,
+                Some(PaletteItem::Pig),
+                locale.tr("sandbox.pig"),
+            );
+        });
+}
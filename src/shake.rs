@@ -0,0 +1,174 @@
+// Impact feel: heavy hits shake the camera, harder the bigger the collision impulse, and
+// defeating a pig or setting off a blast briefly slows physics almost to a stop. Both can
+// be turned down or off in the settings for players sensitive to motion.
+use std::time::Duration;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::{
+    camera::{camera_system, camera_target_system},
+    destruction::{HEAVY_IMPULSE, Impact, PigDefeated},
+    disaster::Blast,
+};
+
+// Shake builds up as "trauma" from 0 to 1 and wears off at TRAUMA_DECAY per second. The
+// camera moves by up to MAX_SHAKE world pixels, scaled by trauma squared so small bumps
+// barely register.
+const TRAUMA_DECAY: f32 = 1.5;
+const MAX_SHAKE: f32 = 20.0;
+// Hits lighter than this don't shake at all; HEAVY_IMPULSE adds IMPACT_TRAUMA
+const SHAKE_IMPULSE: f32 = HEAVY_IMPULSE * 0.2;
+const IMPACT_TRAUMA: f32 = 0.5;
+const DEFEAT_TRAUMA: f32 = 0.3;
+const BLAST_TRAUMA: f32 = 0.8;
+// Physics speed during a hit-stop, and for how long (real time)
+const HIT_STOP_SPEED: f32 = 0.05;
+const DEFEAT_HIT_STOP: Duration = Duration::from_millis(80);
+const BLAST_HIT_STOP: Duration = Duration::from_millis(150);
+
+pub struct ShakePlugin;
+
+impl Plugin for ShakePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<Impact>()
+            .add_message::<PigDefeated>()
+            .add_message::<Blast>()
+            .init_resource::<ShakeSettings>()
+            .init_resource::<ScreenShake>()
+            .init_resource::<HitStop>()
+            .add_systems(
+                Update,
+                (
+                    trauma_system,
+                    hit_stop_system,
+                    // Take the shake out before the camera works out where it should be,
+                    // and put it back on afterwards
+                    unshake_camera_system.before(camera_target_system),
+                    shake_camera_system.after(camera_system),
+                ),
+            );
+    }
+}
+
+#[derive(Resource)]
+pub struct ShakeSettings {
+    pub shake: bool,
+    // Multiplies how far the camera moves, 0 to 2
+    pub intensity: f32,
+    pub hit_stop: bool,
+}
+
+impl Default for ShakeSettings {
+    fn default() -> Self {
+        ShakeSettings {
+            shake: true,
+            intensity: 1.0,
+            hit_stop: true,
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct ScreenShake {
+    trauma: f32,
+    // Currently added to the camera position
+    offset: Vec2,
+}
+
+impl ScreenShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).min(1.0);
+    }
+}
+
+// Time left on the current hit-stop, and the physics speed to go back to
+#[derive(Resource, Default)]
+struct HitStop {
+    remaining: Duration,
+    resume_speed: f32,
+}
+
+fn trauma_system(
+    mut shake: ResMut<ScreenShake>,
+    mut impacts: MessageReader<Impact>,
+    mut defeated: MessageReader<PigDefeated>,
+    mut blasts: MessageReader<Blast>,
+) {
+    for impact in impacts.read() {
+        if impact.impulse > SHAKE_IMPULSE {
+            shake.add_trauma(IMPACT_TRAUMA * (impact.impulse / HEAVY_IMPULSE).min(1.0));
+        }
+    }
+    for _ in defeated.read() {
+        shake.add_trauma(DEFEAT_TRAUMA);
+    }
+    for _ in blasts.read() {
+        shake.add_trauma(BLAST_TRAUMA);
+    }
+}
+
+fn hit_stop_system(
+    real_time: Res<Time<Real>>,
+    settings: Res<ShakeSettings>,
+    mut physics_time: ResMut<Time<Physics>>,
+    mut hit_stop: ResMut<HitStop>,
+    mut defeated: MessageReader<PigDefeated>,
+    mut blasts: MessageReader<Blast>,
+) {
+    let wanted = defeated
+        .read()
+        .map(|_| DEFEAT_HIT_STOP)
+        .chain(blasts.read().map(|_| BLAST_HIT_STOP))
+        .max();
+
+    if !hit_stop.remaining.is_zero() {
+        hit_stop.remaining = hit_stop.remaining.saturating_sub(real_time.delta());
+        // Unless the player picked another speed in the meantime
+        if hit_stop.remaining.is_zero() && physics_time.relative_speed() == HIT_STOP_SPEED {
+            let resume_speed = hit_stop.resume_speed;
+            physics_time.set_relative_speed(resume_speed);
+        }
+    }
+
+    if let Some(duration) = wanted
+        && settings.hit_stop
+        && !physics_time.is_paused()
+    {
+        // A stop landing during another only extends it
+        if hit_stop.remaining.is_zero() {
+            hit_stop.resume_speed = physics_time.relative_speed();
+            physics_time.set_relative_speed(HIT_STOP_SPEED);
+        }
+        hit_stop.remaining = hit_stop.remaining.max(duration);
+    }
+}
+
+fn unshake_camera_system(
+    mut shake: ResMut<ScreenShake>,
+    mut camera_q: Query<&mut Transform, With<Camera2d>>,
+) {
+    for mut transform in camera_q.iter_mut() {
+        transform.translation -= shake.offset.extend(0.0);
+    }
+    shake.offset = Vec2::ZERO;
+}
+
+fn shake_camera_system(
+    time: Res<Time>,
+    settings: Res<ShakeSettings>,
+    mut shake: ResMut<ScreenShake>,
+    mut camera_q: Query<&mut Transform, With<Camera2d>>,
+) {
+    shake.trauma = (shake.trauma - TRAUMA_DECAY * time.delta_secs()).max(0.0);
+    if !settings.shake || shake.trauma == 0.0 {
+        return;
+    }
+    let mut rng = rand::rng();
+    let direction = Vec2::new(rng.random_range(-1.0..=1.0), rng.random_range(-1.0..=1.0));
+    shake.offset = direction * MAX_SHAKE * settings.intensity * shake.trauma.powi(2);
+    for mut transform in camera_q.iter_mut() {
+        transform.translation += shake.offset.extend(0.0);
+    }
+}
@@ -0,0 +1,220 @@
+// Headless simulation: runs a level's physics, destruction rules, disasters and dependency
+// cascades without a window, camera or egui. Each update advances time by exactly one
+// fixed physics step of 1/60s, so results are repeatable.
+use std::time::Duration;
+
+use avian2d::prelude::*;
+use bevy::{ecs::system::RunSystemOnce, prelude::*, time::TimeUpdateStrategy};
+
+use crate::{
+    dependency::DependencyPlugin,
+    destruction::DestructionPlugin,
+    disaster::DisasterPlugin,
+    level::{
+        Block, CurrentLevel, GRAVITY, Invisible, LEVELS, Level, Pig, spawn_ground, spawn_level,
+    },
+    slingshot::{BIRD_REST_POS, Shot, SlingshotState, launch_bird, spawn_bird},
+};
+
+// Both the time each update advances and avian's fixed timestep
+const STEP_SECONDS: f32 = 1.0 / 60.0;
+// Bodies slower than this (pixels/s) count as at rest
+pub const SETTLE_SPEED: f32 = 5.0;
+// Give a shot at least this long before checking whether the level has settled
+const MIN_SHOT_SECONDS: f32 = 1.0;
+// How long one shot may take to play out before the next bird is fired
+pub const MAX_SHOT_SECONDS: f32 = 10.0;
+// How long a level must stand on its own, and how far a block may settle meanwhile
+const STABILITY_SECONDS: f32 = 5.0;
+const MAX_BLOCK_DRIFT: f32 = 10.0;
+
+pub struct Simulation {
+    app: App,
+    shots: usize,
+}
+
+impl Simulation {
+    pub fn new(level: &'static Level) -> Self {
+        let step = Duration::from_secs_f32(STEP_SECONDS);
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            bevy::transform::TransformPlugin,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            bevy::mesh::MeshPlugin,
+            bevy::scene::ScenePlugin,
+            PhysicsPlugins::default(),
+            DestructionPlugin,
+            DisasterPlugin,
+            DependencyPlugin,
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(step))
+        // Bevy's default fixed rate is 64 Hz, which would run a varying number of physics
+        // steps per update
+        .insert_resource(Time::<Fixed>::from_duration(step))
+        .insert_resource(Gravity(GRAVITY))
+        .insert_resource(CurrentLevel(
+            LEVELS.iter().position(|l| l.id == level.id).unwrap_or(0),
+        ))
+        .insert_resource(simulated_slingshot(level))
+        .add_systems(
+            Startup,
+            move |mut commands: Commands, asset_server: Res<AssetServer>| {
+                spawn_ground(&mut commands);
+                spawn_level(&mut commands, &asset_server, level);
+            },
+        );
+
+        app.finish();
+        app.cleanup();
+        // First update runs Startup so the level exists before anyone inspects it
+        app.update();
+
+        Simulation { app, shots: 0 }
+    }
+
+    pub fn run_for(&mut self, seconds: f32) {
+        let steps = (seconds / STEP_SECONDS).ceil() as u32;
+        for _ in 0..steps {
+            self.app.update();
+        }
+    }
+
+    // Fire a fresh bird as if the player had dragged it back by the shot's pull and let go,
+    // carrying the next disaster in the level's queue
+    pub fn shoot(&mut self, shot: Shot) {
+        let pull = shot.pull_vector();
+        if self.shots > 0 {
+            let mut slingshot = self.app.world_mut().resource_mut::<SlingshotState>();
+            slingshot.disaster = slingshot.queue.pop_front();
+        }
+        self.shots += 1;
+        self.app
+            .world_mut()
+            .run_system_once(
+                move |mut commands: Commands, asset_server: Res<AssetServer>| {
+                    let bird = spawn_bird(&mut commands, &asset_server);
+                    let pos = BIRD_REST_POS - pull;
+                    commands
+                        .entity(bird)
+                        .insert(Transform::from_xyz(pos.x, pos.y, 2.0));
+                    launch_bird(&mut commands, bird, pull);
+                },
+            )
+            .unwrap();
+    }
+
+    // Step until every dynamic body has come to rest, or `max_seconds` have passed
+    pub fn run_until_settled(&mut self, max_seconds: f32) {
+        let max_steps = (max_seconds / STEP_SECONDS).ceil() as u32;
+        let min_steps = (MIN_SHOT_SECONDS / STEP_SECONDS).ceil() as u32;
+        for step in 0..max_steps {
+            self.app.update();
+            if step >= min_steps && self.is_settled() {
+                return;
+            }
+        }
+    }
+
+    fn is_settled(&mut self) -> bool {
+        let world = self.app.world_mut();
+        all_settled(world.query::<(&RigidBody, &LinearVelocity)>().iter(world))
+    }
+
+    pub fn world_mut(&mut self) -> &mut World {
+        self.app.world_mut()
+    }
+
+    pub fn pig_count(&mut self) -> usize {
+        let world = self.app.world_mut();
+        world.query_filtered::<(), With<Pig>>().iter(world).count()
+    }
+
+    // Positions of every visible block, keyed by entity so they can be compared across steps
+    pub fn block_positions(&mut self) -> Vec<(Entity, Vec2)> {
+        let world = self.app.world_mut();
+        world
+            .query_filtered::<(Entity, &Transform), (With<Block>, Without<Invisible>)>()
+            .iter(world)
+            .map(|(entity, transform)| (entity, transform.translation.truncate()))
+            .collect()
+    }
+}
+
+// The level's pigs and the disasters they stand for. The game picks the disasters after
+// the first at random, so here they take turns instead to keep runs repeatable.
+fn simulated_slingshot(level: &Level) -> SlingshotState {
+    SlingshotState {
+        disaster: level.disasters.first().copied(),
+        queue: (1..level.birds)
+            .filter_map(|i| {
+                level
+                    .disasters
+                    .get(i % level.disasters.len().max(1))
+                    .copied()
+            })
+            .collect(),
+        ..default()
+    }
+}
+
+// Whether every dynamic body among `bodies` has come to rest. Shared with the camera, so
+// it lets go of a shot at the same moment the simulation would.
+pub fn all_settled<'a>(
+    bodies: impl IntoIterator<Item = (&'a RigidBody, &'a LinearVelocity)>,
+) -> bool {
+    bodies
+        .into_iter()
+        .filter(|(body, _)| body.is_dynamic())
+        .all(|(_, velocity)| velocity.length() < SETTLE_SPEED)
+}
+
+// Spawns the level, lets it stand with no input and describes anything that fell apart.
+// An empty list means the level is stable.
+pub fn check_stability(level: &'static Level) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut sim = Simulation::new(level);
+    let pigs_before = sim.pig_count();
+    let blocks_before = sim.block_positions();
+    if pigs_before == 0 {
+        problems.push("level has no pigs".to_string());
+    }
+
+    sim.run_for(STABILITY_SECONDS);
+
+    let pigs_after = sim.pig_count();
+    if pigs_after < pigs_before {
+        problems.push(format!(
+            "{} pig(s) defeated with no input",
+            pigs_before - pigs_after
+        ));
+    }
+
+    let blocks_after = sim.block_positions();
+    for (entity, start) in blocks_before {
+        match blocks_after.iter().find(|(e, _)| *e == entity) {
+            Some((_, end)) if start.distance(*end) > MAX_BLOCK_DRIFT => {
+                problems.push(format!("block at {start} moved to {end}"));
+            }
+            Some(_) => {}
+            None => problems.push(format!("block at {start} was destroyed")),
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::LEVELS;
+
+    #[test]
+    fn shipped_levels_stand_without_input() {
+        for level in LEVELS {
+            let problems = check_stability(level);
+            assert!(problems.is_empty(), "{}: {problems:#?}", level.name);
+        }
+    }
+}
@@ -0,0 +1,375 @@
+// The slingshot: spawning birds, aiming with a drag or with buttons, and launching.
+use std::collections::VecDeque;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use rand::seq::IndexedRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    disaster::Disaster,
+    level::{CurrentLevel, LEVELS, Level},
+    menu::GameState,
+    sandbox::Sandbox,
+};
+
+pub const SLINGSHOT_POS: Vec2 = Vec2::new(-300.0, -220.0);
+// Where a waiting bird sits in the slingshot
+pub const BIRD_REST_POS: Vec2 = Vec2::new(SLINGSHOT_POS.x, SLINGSHOT_POS.y + 100.0);
+// Launch velocity per pixel of pull
+pub const LAUNCH_FORCE: f32 = 15.0;
+// Keyboard/gamepad aiming limits and speeds (degrees and pixels of pull per second)
+const MIN_AIM_ANGLE: f32 = -30.0;
+const MAX_AIM_ANGLE: f32 = 90.0;
+const MIN_PULL: f32 = 10.0;
+const MAX_PULL: f32 = 150.0;
+const AIM_ANGLE_SPEED: f32 = 45.0;
+const AIM_PULL_SPEED: f32 = 80.0;
+
+pub struct SlingshotPlugin;
+
+impl Plugin for SlingshotPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SlingshotState>()
+            .insert_resource(RespawnTimer(Timer::from_seconds(2.0, TimerMode::Once)))
+            .init_resource::<AimMode>()
+            .init_resource::<ButtonAim>()
+            .add_systems(Startup, spawn_slingshot)
+            .add_systems(
+                Update,
+                (
+                    (input_system, button_aim_system, trajectory_system).chain(),
+                    respawn_bird_system,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn spawn_slingshot(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    current_level: Res<CurrentLevel>,
+    mut slingshot_state: ResMut<SlingshotState>,
+) {
+    let slingshot_pos = SLINGSHOT_POS;
+
+    // Right part (Back)
+    commands.spawn((
+        Sprite::from_image(asset_server.load("slingshot_right.png")),
+        Transform::from_xyz(slingshot_pos.x + 20.0, slingshot_pos.y + 30.0, 1.0),
+        Slingshot,
+    ));
+
+    // Left part (Front)
+    commands.spawn((
+        Sprite::from_image(asset_server.load("slingshot_left.png")),
+        Transform::from_xyz(slingshot_pos.x - 5.0, slingshot_pos.y + 80.0, 3.0), // Higher Z to be in front of bird
+        Slingshot,
+    ));
+
+    // Bird (Ready to launch)
+    spawn_bird(&mut commands, &asset_server);
+    load_slingshot(&mut slingshot_state, &LEVELS[current_level.0]);
+}
+
+#[derive(Component)]
+pub struct Bird;
+
+#[derive(Component)]
+pub struct OnSlingshot;
+
+#[derive(Component)]
+pub struct Slingshot;
+
+#[derive(Resource, Default)]
+pub struct SlingshotState {
+    pub is_dragging: bool,
+    pub start_pos: Vec2,
+    // The disaster the waiting pig stands for, which plays out when it hits something
+    pub disaster: Option<Disaster>,
+    // Disasters of the pigs still to come after the waiting one, in launch order
+    pub queue: VecDeque<Disaster>,
+    // How far the bird is drawn back right now, if the player is aiming
+    pub pull: Option<Vec2>,
+}
+
+// A single launch: the direction the bird flies and how far the slingshot was pulled
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Shot {
+    pub angle_degrees: f32,
+    pub pull: f32,
+}
+
+impl Shot {
+    pub fn pull_vector(&self) -> Vec2 {
+        Vec2::from_angle(self.angle_degrees.to_radians()) * self.pull
+    }
+}
+
+// How the player aims, picked in the settings window
+#[derive(Resource, Default, Clone, Copy, PartialEq)]
+pub enum AimMode {
+    // Drag the bird back with the mouse or a finger
+    #[default]
+    Drag,
+    // Arrow keys or a gamepad stick set angle and power
+    Buttons,
+}
+
+// The shot lined up with the keyboard or gamepad
+#[derive(Resource)]
+pub struct ButtonAim(pub Shot);
+
+impl Default for ButtonAim {
+    fn default() -> Self {
+        ButtonAim(Shot {
+            angle_degrees: 30.0,
+            pull: 80.0,
+        })
+    }
+}
+
+#[derive(Resource)]
+pub struct RespawnTimer(pub Timer);
+
+// Loads the level's first disaster and rolls random ones for the rest of its pigs
+pub fn load_slingshot(state: &mut SlingshotState, level: &Level) {
+    let mut rng = rand::rng();
+    state.disaster = level.disasters.first().copied();
+    state.queue = (1..level.birds)
+        .filter_map(|_| level.disasters.choose(&mut rng).copied())
+        .collect();
+}
+
+pub fn spawn_bird(commands: &mut Commands, asset_server: &Res<AssetServer>) -> Entity {
+    commands
+        .spawn((
+            Sprite::from_image(asset_server.load("pigs/pig_silly.png")),
+            Transform::from_xyz(BIRD_REST_POS.x, BIRD_REST_POS.y, 2.0),
+            RigidBody::Kinematic, // Kinematic while waiting
+            Collider::circle(22.0),
+            CollidingEntities::default(),
+            SweptCcd::default(),
+            ColliderDensity(5.0),
+            Bird,
+            OnSlingshot,
+        ))
+        .id()
+}
+
+// Take the bird off the slingshot and send it flying, `pull` is how far it was drawn back
+pub fn launch_bird(commands: &mut Commands, entity: Entity, pull: Vec2) {
+    commands
+        .entity(entity)
+        .insert((RigidBody::Dynamic, LinearVelocity(pull * LAUNCH_FORCE)))
+        .remove::<OnSlingshot>();
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn respawn_bird_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut timer: ResMut<RespawnTimer>,
+    bird_q: Query<Entity, With<OnSlingshot>>,
+    mut slingshot_state: ResMut<SlingshotState>,
+    current_level: Res<CurrentLevel>,
+    sandbox: Res<Sandbox>,
+) {
+    if bird_q.iter().next().is_some() {
+        // defined a bird, so reset timer
+        timer.0.reset();
+        return;
+    }
+
+    timer.0.tick(time.delta());
+    if timer.0.just_finished() {
+        let next = match slingshot_state.queue.pop_front() {
+            Some(disaster) => Some(disaster),
+            // The sandbox never runs out
+            None if sandbox.enabled => LEVELS[current_level.0]
+                .disasters
+                .choose(&mut rand::rng())
+                .copied(),
+            None => None,
+        };
+        if let Some(disaster) = next {
+            spawn_bird(&mut commands, &asset_server);
+            slingshot_state.disaster = Some(disaster);
+        }
+    }
+}
+
+// The mouse cursor or a single finger, whichever the player is using
+pub struct Pointer {
+    pub pos: Vec2,
+    pub just_pressed: bool,
+    pub pressed: bool,
+}
+
+impl Pointer {
+    pub fn from_input(
+        window: &Window,
+        mouse_button: &ButtonInput<MouseButton>,
+        touches: &Touches,
+    ) -> Option<Self> {
+        // Two or more fingers belong to the camera's pan and pinch. There's no pointer
+        // meanwhile, so a drag under way is paused rather than let go.
+        let mut active = touches.iter();
+        match (active.next(), active.next()) {
+            (Some(touch), None) => {
+                return Some(Pointer {
+                    pos: touch.position(),
+                    just_pressed: touches.just_pressed(touch.id()),
+                    pressed: true,
+                });
+            }
+            (Some(_), Some(_)) => return None,
+            _ => {}
+        }
+        if let Some(touch) = touches.iter_just_released().next() {
+            return Some(Pointer {
+                pos: touch.position(),
+                just_pressed: false,
+                pressed: false,
+            });
+        }
+
+        window.cursor_position().map(|pos| Pointer {
+            pos,
+            just_pressed: mouse_button.just_pressed(MouseButton::Left),
+            pressed: mouse_button.pressed(MouseButton::Left),
+        })
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn input_system(
+    mut commands: Commands,
+    mut drag_state: ResMut<SlingshotState>,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    touches: Res<Touches>,
+    aim_mode: Res<AimMode>,
+    mut bird_q: Query<(Entity, &mut Transform), (With<Bird>, With<OnSlingshot>)>,
+) {
+    if *aim_mode != AimMode::Drag {
+        return;
+    }
+    if !drag_state.is_dragging {
+        drag_state.pull = None;
+    }
+    let Some((camera, camera_transform)) = camera_q.iter().next() else {
+        return;
+    };
+    let Some(window) = windows.iter().next() else {
+        return;
+    };
+
+    if let Some(pointer) = Pointer::from_input(window, &mouse_button, &touches) {
+        if let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, pointer.pos) {
+            if pointer.just_pressed {
+                // Check if clicking near bird (simplified)
+                if let Some((_, transform)) = bird_q.iter().next() {
+                    if transform.translation.truncate().distance(world_pos) < 50.0 {
+                        drag_state.is_dragging = true;
+                        drag_state.start_pos = world_pos;
+                    }
+                }
+            }
+
+            if drag_state.is_dragging {
+                if pointer.pressed {
+                    // Drag bird
+                    if let Some((_, mut transform)) = bird_q.iter_mut().next() {
+                        transform.translation.x = world_pos.x;
+                        transform.translation.y = world_pos.y;
+                    }
+                    drag_state.pull = Some(drag_state.start_pos - world_pos);
+                } else {
+                    // Release
+                    drag_state.is_dragging = false;
+                    drag_state.pull = None;
+                    if let Some((entity, _)) = bird_q.iter().next() {
+                        launch_bird(&mut commands, entity, drag_state.start_pos - world_pos);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Arrow keys or the left stick: up/down sets the angle, right/left pulls harder or softer.
+// Enter or the gamepad's south button launches.
+#[allow(clippy::too_many_arguments)]
+pub fn button_aim_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    aim_mode: Res<AimMode>,
+    mut aim: ResMut<ButtonAim>,
+    mut slingshot: ResMut<SlingshotState>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut bird_q: Query<(Entity, &mut Transform), (With<Bird>, With<OnSlingshot>)>,
+) {
+    if *aim_mode != AimMode::Buttons {
+        return;
+    }
+    let Some((entity, mut transform)) = bird_q.iter_mut().next() else {
+        slingshot.pull = None;
+        return;
+    };
+
+    let key_axis = |negative, positive| {
+        keyboard.pressed(positive) as i32 as f32 - keyboard.pressed(negative) as i32 as f32
+    };
+    let mut input = Vec2::new(
+        key_axis(KeyCode::ArrowLeft, KeyCode::ArrowRight),
+        key_axis(KeyCode::ArrowDown, KeyCode::ArrowUp),
+    );
+    let mut launch = keyboard.just_pressed(KeyCode::Enter);
+    for gamepad in gamepads.iter() {
+        input += gamepad.left_stick() + gamepad.dpad();
+        launch |= gamepad.just_pressed(GamepadButton::South);
+    }
+    let input = input.clamp(Vec2::NEG_ONE, Vec2::ONE);
+
+    let shot = &mut aim.0;
+    shot.angle_degrees = (shot.angle_degrees + input.y * AIM_ANGLE_SPEED * time.delta_secs())
+        .clamp(MIN_AIM_ANGLE, MAX_AIM_ANGLE);
+    shot.pull =
+        (shot.pull + input.x * AIM_PULL_SPEED * time.delta_secs()).clamp(MIN_PULL, MAX_PULL);
+
+    let pull = shot.pull_vector();
+    if launch {
+        slingshot.pull = None;
+        launch_bird(&mut commands, entity, pull);
+    } else {
+        // Show the bird drawn back, as if it had been dragged there
+        let pos = BIRD_REST_POS - pull;
+        transform.translation.x = pos.x;
+        transform.translation.y = pos.y;
+        slingshot.pull = Some(pull);
+    }
+}
+
+// Dotted arc of where the bird would fly if released now, whichever way it's being aimed
+pub fn trajectory_system(
+    mut gizmos: Gizmos,
+    slingshot: Res<SlingshotState>,
+    gravity: Res<Gravity>,
+    bird_q: Query<&Transform, (With<Bird>, With<OnSlingshot>)>,
+) {
+    let (Some(pull), Some(transform)) = (slingshot.pull, bird_q.iter().next()) else {
+        return;
+    };
+    let start = transform.translation.truncate();
+    let velocity = pull * LAUNCH_FORCE;
+    for i in 1..=20 {
+        let t = i as f32 * 0.05;
+        let pos = start + velocity * t + 0.5 * gravity.0 * t * t;
+        gizmos.circle_2d(pos, 4.0, Color::WHITE);
+    }
+}
@@ -0,0 +1,162 @@
+// Shot solver: searches launch angle and pull strength for each bird in turn using the
+// headless simulation. Simulations can't be cloned, so every candidate replays the shots
+// chosen so far from a freshly spawned level.
+use serde::Serialize;
+
+use crate::{
+    level::Level,
+    sim::{MAX_SHOT_SECONDS, Simulation},
+    slingshot::Shot,
+};
+
+// Coarse grid over the launch space: (min, max, step)
+const ANGLE_RANGE: (f32, f32, f32) = (-10.0, 80.0, 5.0);
+const PULL_RANGE: (f32, f32, f32) = (30.0, 150.0, 15.0);
+
+#[derive(Debug, Serialize)]
+pub struct SolverReport {
+    pub shots: Vec<Shot>,
+    pub pigs_total: usize,
+    pub pigs_remaining: usize,
+}
+
+impl SolverReport {
+    pub fn solved(&self) -> bool {
+        self.pigs_remaining == 0
+    }
+}
+
+// Greedily pick the shot that defeats the most pigs, one bird at a time, stopping as soon
+// as the level is cleared, a bird makes no progress, or `max_birds` have been used
+pub fn solve(level: &'static Level, max_birds: usize) -> SolverReport {
+    let pigs_total = Simulation::new(level).pig_count();
+    let mut report = SolverReport {
+        shots: Vec::new(),
+        pigs_total,
+        pigs_remaining: pigs_total,
+    };
+
+    while !report.solved() && report.shots.len() < max_birds {
+        let Some((shot, remaining)) = best_next_shot(level, &report.shots) else {
+            break;
+        };
+        if remaining >= report.pigs_remaining {
+            break;
+        }
+        report.shots.push(shot);
+        report.pigs_remaining = remaining;
+    }
+
+    report
+}
+
+fn best_next_shot(level: &'static Level, played: &[Shot]) -> Option<(Shot, usize)> {
+    let mut best = search(level, played, grid(ANGLE_RANGE, PULL_RANGE))?;
+
+    // Refine around the best coarse shot with a grid half the step size
+    let coarse = best.0;
+    let (angle, pull) = (coarse.angle_degrees, coarse.pull);
+    let half_angle = ANGLE_RANGE.2 / 2.0;
+    let half_pull = PULL_RANGE.2 / 2.0;
+    let neighbours = grid(
+        (angle - half_angle, angle + half_angle, half_angle),
+        (pull - half_pull, pull + half_pull, half_pull),
+    )
+    .filter(move |shot| *shot != coarse);
+    if let Some(refined) = search(level, played, neighbours)
+        && refined.1 < best.1
+    {
+        best = refined;
+    }
+
+    Some(best)
+}
+
+// Returns the candidate leaving the fewest pigs, preferring earlier candidates on ties
+fn search(
+    level: &'static Level,
+    played: &[Shot],
+    candidates: impl Iterator<Item = Shot>,
+) -> Option<(Shot, usize)> {
+    let mut best: Option<(Shot, usize)> = None;
+    for shot in candidates {
+        let remaining = play(level, played.iter().copied().chain([shot]));
+        if best.is_none_or(|(_, best_remaining)| remaining < best_remaining) {
+            best = Some((shot, remaining));
+        }
+        if remaining == 0 {
+            break;
+        }
+    }
+    best
+}
+
+// Plays the shots in order on a fresh level and returns how many pigs survive
+fn play(level: &'static Level, shots: impl Iterator<Item = Shot>) -> usize {
+    let mut sim = Simulation::new(level);
+    for shot in shots {
+        sim.shoot(shot);
+        sim.run_until_settled(MAX_SHOT_SECONDS);
+        if sim.pig_count() == 0 {
+            break;
+        }
+    }
+    sim.pig_count()
+}
+
+fn grid(angles: (f32, f32, f32), pulls: (f32, f32, f32)) -> impl Iterator<Item = Shot> {
+    steps(angles).flat_map(move |angle_degrees| {
+        steps(pulls).map(move |pull| Shot {
+            angle_degrees,
+            pull,
+        })
+    })
+}
+
+fn steps((min, max, step): (f32, f32, f32)) -> impl Iterator<Item = f32> + Clone {
+    let count = ((max - min) / step).round() as u32;
+    (0..=count).map(move |i| min + i as f32 * step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::LEVELS;
+
+    // Slow, it runs thousands of simulations, but it's the only check that every level can
+    // be cleared with the birds it gives the player
+    #[test]
+    fn shipped_levels_are_beatable() {
+        for level in LEVELS {
+            let report = solve(level, level.birds);
+            assert!(
+                report.solved(),
+                "{}: best found leaves {}/{} pigs after {:?}",
+                level.name,
+                report.pigs_remaining,
+                report.pigs_total,
+                report.shots
+            );
+        }
+    }
+
+    #[test]
+    fn grid_covers_both_ends_of_each_range() {
+        let shots: Vec<Shot> = grid((0.0, 10.0, 5.0), (30.0, 60.0, 15.0)).collect();
+        assert_eq!(shots.len(), 9);
+        assert_eq!(
+            shots.first(),
+            Some(&Shot {
+                angle_degrees: 0.0,
+                pull: 30.0
+            })
+        );
+        assert_eq!(
+            shots.last(),
+            Some(&Shot {
+                angle_degrees: 10.0,
+                pull: 60.0
+            })
+        );
+    }
+}
@@ -0,0 +1,302 @@
+// Sound: impacts that sound like the material hit and get louder with the collision
+// impulse, the slingshot, breaking blocks and defeated pigs, and looping music for the
+// menus and levels. Every file is named in the audio manifest by sound id.
+use std::collections::HashMap;
+
+use bevy::{audio::Volume, prelude::*};
+use serde::Deserialize;
+
+use crate::{
+    content::JsonLoader,
+    destruction::{BlockBroken, HEAVY_IMPULSE, Impact, PigDefeated, Surface},
+    level::BlockMaterial,
+    menu::GameState,
+    slingshot::{Bird, OnSlingshot, SlingshotState},
+};
+
+const MANIFEST_PATH: &str = "audio/sounds.audio.json";
+pub const MUTE_KEY: KeyCode = KeyCode::KeyM;
+// So a collapsing tower plays its biggest hits rather than hundreds of sounds at once
+const MAX_IMPACTS_PER_FRAME: usize = 4;
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<AudioManifest>()
+            .register_asset_loader(JsonLoader::<AudioManifest>::new(&["audio.json"]))
+            .add_message::<BlockBroken>()
+            .add_message::<PigDefeated>()
+            .add_message::<Impact>()
+            .init_resource::<AudioSettings>()
+            .add_systems(Startup, load_manifest)
+            .add_systems(
+                Update,
+                (
+                    load_sounds_system,
+                    mute_key_system,
+                    music_system,
+                    impact_sound_system,
+                    slingshot_sound_system,
+                    destruction_sound_system,
+                ),
+            );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Sound {
+    ImpactSteel,
+    ImpactWood,
+    ImpactPig,
+    ImpactGround,
+    SlingshotStretch,
+    SlingshotRelease,
+    PigDefeat,
+    BreakWood,
+    BreakSteel,
+    MenuMusic,
+    LevelMusic,
+}
+
+impl Sound {
+    pub const ALL: &[Sound] = &[
+        Sound::ImpactSteel,
+        Sound::ImpactWood,
+        Sound::ImpactPig,
+        Sound::ImpactGround,
+        Sound::SlingshotStretch,
+        Sound::SlingshotRelease,
+        Sound::PigDefeat,
+        Sound::BreakWood,
+        Sound::BreakSteel,
+        Sound::MenuMusic,
+        Sound::LevelMusic,
+    ];
+}
+
+// Sound id to file path, relative to the assets folder
+#[derive(Asset, TypePath, Deserialize)]
+#[serde(transparent)]
+pub struct AudioManifest(HashMap<Sound, String>);
+
+// Volumes are linear, 0 to 1, on top of the master volume in GlobalVolume
+#[derive(Resource)]
+pub struct AudioSettings {
+    pub music: f32,
+    pub effects: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        AudioSettings {
+            music: 0.5,
+            effects: 1.0,
+            muted: false,
+        }
+    }
+}
+
+// Sounds load once the manifest has; until then nothing plays
+#[derive(Resource)]
+pub struct Sounds {
+    manifest: Handle<AudioManifest>,
+    handles: HashMap<Sound, Handle<AudioSource>>,
+}
+
+#[derive(Component)]
+struct Music(Sound);
+
+fn load_manifest(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(Sounds {
+        manifest: asset_server.load(MANIFEST_PATH),
+        handles: HashMap::new(),
+    });
+}
+
+fn load_sounds_system(
+    asset_server: Res<AssetServer>,
+    manifests: Res<Assets<AudioManifest>>,
+    mut sounds: ResMut<Sounds>,
+) {
+    if !sounds.handles.is_empty() {
+        return;
+    }
+    let Some(manifest) = manifests.get(&sounds.manifest) else {
+        return;
+    };
+    sounds.handles = manifest
+        .0
+        .iter()
+        .map(|(&sound, path)| (sound, asset_server.load(path)))
+        .collect();
+}
+
+fn mute_key_system(keyboard: Res<ButtonInput<KeyCode>>, mut settings: ResMut<AudioSettings>) {
+    if keyboard.just_pressed(MUTE_KEY) {
+        settings.muted = !settings.muted;
+    }
+}
+
+// One-shot effect at `volume` times the effects volume
+fn play(
+    commands: &mut Commands,
+    sounds: &Sounds,
+    settings: &AudioSettings,
+    sound: Sound,
+    volume: f32,
+) {
+    if settings.muted {
+        return;
+    }
+    if let Some(handle) = sounds.handles.get(&sound) {
+        commands.spawn((
+            AudioPlayer(handle.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(volume * settings.effects)),
+        ));
+    }
+}
+
+// Menu music in the menus and level music in play. Settings can open from either, so it
+// keeps whichever is already playing. Muting stops the music and unmuting starts it over.
+fn music_system(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    sounds: Res<Sounds>,
+    settings: Res<AudioSettings>,
+    global_volume: Res<GlobalVolume>,
+    mut music_q: Query<(Entity, &Music, Option<&mut AudioSink>)>,
+) {
+    let wanted = match state.get() {
+        GameState::MainMenu | GameState::LevelSelect => Some(Sound::MenuMusic),
+        GameState::Playing | GameState::Paused => Some(Sound::LevelMusic),
+        GameState::Settings => None,
+    };
+    let mut playing = false;
+    for (entity, music, sink) in music_q.iter_mut() {
+        if settings.muted || wanted.is_some_and(|wanted| wanted != music.0) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        playing = true;
+        // The master volume only applies by itself when a sound starts
+        if let Some(mut sink) = sink
+            && (settings.is_changed() || global_volume.is_changed())
+        {
+            sink.set_volume(global_volume.volume * Volume::Linear(settings.music));
+        }
+    }
+
+    let track = wanted.unwrap_or(Sound::MenuMusic);
+    if !playing
+        && !settings.muted
+        && let Some(handle) = sounds.handles.get(&track)
+    {
+        commands.spawn((
+            AudioPlayer(handle.clone()),
+            PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.music)),
+            Music(track),
+        ));
+    }
+}
+
+// Played louder the harder the hit
+fn impact_sound_system(
+    mut commands: Commands,
+    sounds: Res<Sounds>,
+    settings: Res<AudioSettings>,
+    mut impacts: MessageReader<Impact>,
+) {
+    let mut heaviest: Vec<&Impact> = impacts.read().collect();
+    heaviest.sort_by(|a, b| b.impulse.total_cmp(&a.impulse));
+
+    for impact in heaviest.into_iter().take(MAX_IMPACTS_PER_FRAME) {
+        let sound = match impact.surfaces.iter().min() {
+            Some(Surface::Steel) => Sound::ImpactSteel,
+            Some(Surface::Wood) => Sound::ImpactWood,
+            Some(Surface::Creature) => Sound::ImpactPig,
+            _ => Sound::ImpactGround,
+        };
+        let volume = (impact.impulse / HEAVY_IMPULSE).clamp(0.1, 1.0);
+        play(&mut commands, &sounds, &settings, sound, volume);
+    }
+}
+
+// Stretching as soon as the player starts aiming, and the snap of a launch
+fn slingshot_sound_system(
+    mut commands: Commands,
+    sounds: Res<Sounds>,
+    settings: Res<AudioSettings>,
+    slingshot: Res<SlingshotState>,
+    mut launched: RemovedComponents<OnSlingshot>,
+    bird_q: Query<(), With<Bird>>,
+    mut was_aiming: Local<bool>,
+) {
+    let aiming = slingshot.pull.is_some();
+    if aiming && !*was_aiming {
+        play(
+            &mut commands,
+            &sounds,
+            &settings,
+            Sound::SlingshotStretch,
+            1.0,
+        );
+    }
+    *was_aiming = aiming;
+
+    for entity in launched.read() {
+        if bird_q.contains(entity) {
+            play(
+                &mut commands,
+                &sounds,
+                &settings,
+                Sound::SlingshotRelease,
+                1.0,
+            );
+        }
+    }
+}
+
+fn destruction_sound_system(
+    mut commands: Commands,
+    sounds: Res<Sounds>,
+    settings: Res<AudioSettings>,
+    mut broken: MessageReader<BlockBroken>,
+    mut defeated: MessageReader<PigDefeated>,
+) {
+    for block in broken.read() {
+        let sound = match block.material {
+            BlockMaterial::Steel => Sound::BreakSteel,
+            _ => Sound::BreakWood,
+        };
+        play(&mut commands, &sounds, &settings, sound, 1.0);
+    }
+    for _ in defeated.read() {
+        play(&mut commands, &sounds, &settings, Sound::PigDefeat, 1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_names_a_shipped_file_for_every_sound() {
+        let manifest: AudioManifest =
+            serde_json::from_slice(include_bytes!("../assets/audio/sounds.audio.json")).unwrap();
+        for sound in Sound::ALL {
+            let path = manifest.0.get(sound).unwrap_or_else(|| {
+                panic!("the audio manifest has no file for {sound:?}");
+            });
+            let on_disk = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("assets")
+                .join(path);
+            assert!(
+                on_disk.is_file(),
+                "{sound:?} plays {path}, which doesn't exist"
+            );
+        }
+    }
+}
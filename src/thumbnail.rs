@@ -0,0 +1,75 @@
+// Level thumbnails drawn on the CPU from the colliders of a freshly spawned level, so
+// layouts can be previewed without a window or GPU.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use image::{Rgba, RgbaImage};
+
+use crate::{
+    level::{BlockMaterial, Level, Pig},
+    sim::Simulation,
+};
+
+const WIDTH: u32 = 480;
+const HEIGHT: u32 = 320;
+// World units of empty space kept around the level
+const MARGIN: f32 = 40.0;
+
+const SKY: Rgba<u8> = Rgba([150, 200, 240, 255]);
+const GROUND: Rgba<u8> = Rgba([50, 204, 50, 255]);
+const WOOD: Rgba<u8> = Rgba([190, 130, 70, 255]);
+const STEEL: Rgba<u8> = Rgba([150, 155, 165, 255]);
+const PIG: Rgba<u8> = Rgba([110, 200, 60, 255]);
+
+pub fn render(level: &'static Level) -> RgbaImage {
+    let mut sim = Simulation::new(level);
+    let world = sim.world_mut();
+    let shapes: Vec<(ColliderAabb, Rgba<u8>, bool)> = world
+        .query::<(&Collider, &Transform, Option<&BlockMaterial>, Has<Pig>)>()
+        .iter(world)
+        .filter_map(|(collider, transform, material, is_pig)| {
+            let color = match (material, is_pig) {
+                (_, true) => PIG,
+                (Some(BlockMaterial::Wood), _) => WOOD,
+                (Some(BlockMaterial::Steel), _) => STEEL,
+                (Some(BlockMaterial::Invisible), _) => return None,
+                (None, false) => GROUND,
+            };
+            let rotation = Rotation::radians(transform.rotation.to_euler(EulerRot::XYZ).2);
+            let aabb = collider.aabb(transform.translation.truncate(), rotation);
+            Some((aabb, color, is_pig))
+        })
+        .collect();
+
+    // Frame the blocks and pigs; the ground is far wider than any level so it's left out
+    let (min, max) = shapes.iter().filter(|(_, color, _)| *color != GROUND).fold(
+        (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+        |(min, max), (aabb, _, _)| (min.min(aabb.min), max.max(aabb.max)),
+    );
+    let (min, max) = (min - MARGIN, max + MARGIN);
+    let scale = (WIDTH as f32 / (max.x - min.x)).min(HEIGHT as f32 / (max.y - min.y));
+    // Centre the level horizontally and sit it on the bottom edge
+    let offset = Vec2::new((WIDTH as f32 - (max.x - min.x) * scale) / 2.0, 0.0);
+    let to_pixel = |pos: Vec2| (pos - min) * scale + offset;
+
+    let mut image = RgbaImage::from_pixel(WIDTH, HEIGHT, SKY);
+    for (aabb, color, is_pig) in shapes {
+        let (low, high) = (to_pixel(aabb.min), to_pixel(aabb.max));
+        let centre = (low + high) / 2.0;
+        let radius = ((high - low) / 2.0).min_element();
+        for x in pixel_range(low.x, high.x, WIDTH) {
+            for y in pixel_range(low.y, high.y, HEIGHT) {
+                let pixel = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                if !is_pig || pixel.distance(centre) <= radius {
+                    // Image rows run top to bottom, world y runs bottom to top
+                    image.put_pixel(x, HEIGHT - 1 - y, color);
+                }
+            }
+        }
+    }
+    image
+}
+
+fn pixel_range(low: f32, high: f32, size: u32) -> std::ops::Range<u32> {
+    let clamp = |v: f32| v.round().clamp(0.0, size as f32) as u32;
+    clamp(low)..clamp(high)
+}
@@ -0,0 +1,310 @@
+// Physics time controls: pause, single-step, slow-motion and fast-forward, and a rewind
+// buffer of recent physics ticks so a collapse can be scrubbed backward and forward.
+use std::{collections::VecDeque, time::Duration};
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use crate::{locale::Localizer, menu::GameState};
+
+// Physics speed multipliers, cycled with SLOWER_KEY / FASTER_KEY
+const SPEEDS: &[f32] = &[0.1, 0.25, 0.5, 1.0, 2.0, 4.0];
+const PAUSE_KEY: KeyCode = KeyCode::Space;
+const STEP_KEY: KeyCode = KeyCode::KeyS;
+const SLOWER_KEY: KeyCode = KeyCode::BracketLeft;
+const FASTER_KEY: KeyCode = KeyCode::BracketRight;
+const REWIND_KEY: KeyCode = KeyCode::Comma;
+const FORWARD_KEY: KeyCode = KeyCode::Period;
+// How far back the rewind buffer reaches. Ticks are recorded once per fixed timestep, so
+// the number kept depends on `Time<Fixed>`.
+const REWIND_SECONDS: f32 = 10.0;
+
+pub struct TimeControlPlugin;
+
+impl Plugin for TimeControlPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RewindBuffer>()
+            .add_systems(
+                FixedPostUpdate,
+                record_rewind_system.after(PhysicsSystems::Last),
+            )
+            .add_systems(
+                Update,
+                (time_control_system, apply_rewind_system)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                EguiPrimaryContextPass,
+                time_ui_system.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+// Where one dynamic body was, and how it was moving, at the end of a tick
+struct BodySnapshot {
+    entity: Entity,
+    position: Vec2,
+    rotation: Rotation,
+    linear_velocity: Vec2,
+    angular_velocity: f32,
+}
+
+#[derive(Resource, Default)]
+pub struct RewindBuffer {
+    ticks: VecDeque<Vec<BodySnapshot>>,
+    // Tick being shown while scrubbing, None while the simulation is live
+    cursor: Option<usize>,
+}
+
+impl RewindBuffer {
+    pub fn len(&self) -> usize {
+        self.ticks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ticks.is_empty()
+    }
+
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor
+    }
+
+    // Moves the shown tick by `offset`, starting from the newest when live
+    pub fn scrub(&mut self, offset: isize) {
+        if self.ticks.is_empty() {
+            return;
+        }
+        let last = self.ticks.len() - 1;
+        let current = self.cursor.unwrap_or(last);
+        self.cursor = Some(current.saturating_add_signed(offset).min(last));
+    }
+
+    pub fn scrub_to(&mut self, tick: usize) {
+        if !self.ticks.is_empty() {
+            self.cursor = Some(tick.min(self.ticks.len() - 1));
+        }
+    }
+
+    // Goes live again from the shown tick, forgetting everything after it
+    pub fn resume(&mut self) {
+        if let Some(cursor) = self.cursor.take() {
+            self.ticks.truncate(cursor + 1);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.ticks.clear();
+        self.cursor = None;
+    }
+}
+
+fn record_rewind_system(
+    time: Res<Time<Physics>>,
+    fixed_time: Res<Time<Fixed>>,
+    mut buffer: ResMut<RewindBuffer>,
+    body_q: Query<(
+        Entity,
+        &RigidBody,
+        &Position,
+        &Rotation,
+        &LinearVelocity,
+        &AngularVelocity,
+    )>,
+) {
+    if time.is_paused() || buffer.cursor.is_some() {
+        return;
+    }
+    let tick = body_q
+        .iter()
+        .filter(|(_, body, ..)| body.is_dynamic())
+        .map(
+            |(entity, _, position, rotation, linear_velocity, angular_velocity)| BodySnapshot {
+                entity,
+                position: position.0,
+                rotation: *rotation,
+                linear_velocity: linear_velocity.0,
+                angular_velocity: angular_velocity.0,
+            },
+        )
+        .collect();
+    buffer.ticks.push_back(tick);
+    let max_ticks = (REWIND_SECONDS / fixed_time.timestep().as_secs_f32()).round() as usize;
+    while buffer.ticks.len() > max_ticks {
+        buffer.ticks.pop_front();
+    }
+}
+
+// Puts every body back where it was at the shown tick. Blocks and pigs destroyed since
+// then stay gone; only bodies still in the world are moved.
+fn apply_rewind_system(
+    buffer: Res<RewindBuffer>,
+    mut body_q: Query<(
+        &mut Transform,
+        &mut Position,
+        &mut Rotation,
+        &mut LinearVelocity,
+        &mut AngularVelocity,
+    )>,
+) {
+    if !buffer.is_changed() {
+        return;
+    }
+    let Some(tick) = buffer.cursor.and_then(|cursor| buffer.ticks.get(cursor)) else {
+        return;
+    };
+    for snapshot in tick {
+        let Ok((mut transform, mut position, mut rotation, mut linear, mut angular)) =
+            body_q.get_mut(snapshot.entity)
+        else {
+            continue;
+        };
+        position.0 = snapshot.position;
+        *rotation = snapshot.rotation;
+        linear.0 = snapshot.linear_velocity;
+        angular.0 = snapshot.angular_velocity;
+        transform.translation.x = snapshot.position.x;
+        transform.translation.y = snapshot.position.y;
+        transform.rotation = Quat::from_rotation_z(snapshot.rotation.as_radians());
+    }
+}
+
+pub fn time_control_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    fixed_time: Res<Time<Fixed>>,
+    mut time: ResMut<Time<Physics>>,
+    mut buffer: ResMut<RewindBuffer>,
+) {
+    if keyboard.just_pressed(PAUSE_KEY) {
+        toggle_pause(&mut time, &mut buffer);
+    }
+
+    if keyboard.just_pressed(STEP_KEY) && time.is_paused() {
+        step(&mut time, &mut buffer, fixed_time.timestep());
+    }
+
+    if keyboard.just_pressed(REWIND_KEY) {
+        time.pause();
+        buffer.scrub(-1);
+    }
+    if keyboard.just_pressed(FORWARD_KEY) {
+        time.pause();
+        buffer.scrub(1);
+    }
+
+    if keyboard.just_pressed(SLOWER_KEY) {
+        change_speed(&mut time, -1);
+    }
+    if keyboard.just_pressed(FASTER_KEY) {
+        change_speed(&mut time, 1);
+    }
+}
+
+fn toggle_pause(time: &mut Time<Physics>, buffer: &mut RewindBuffer) {
+    if time.is_paused() {
+        buffer.resume();
+        time.unpause();
+    } else {
+        time.pause();
+    }
+}
+
+// Steps forward through the rewind buffer while scrubbing, otherwise advances physics by
+// one fixed timestep
+fn step(time: &mut Time<Physics>, buffer: &mut RewindBuffer, timestep: Duration) {
+    match buffer.cursor {
+        Some(cursor) if cursor + 1 < buffer.len() => buffer.scrub(1),
+        _ => {
+            buffer.resume();
+            time.advance_by(timestep);
+        }
+    }
+}
+
+// Moves `offset` places through SPEEDS from the closest preset to the current speed
+fn change_speed(time: &mut Time<Physics>, offset: isize) {
+    let current = time.relative_speed();
+    let index = SPEEDS
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - current).abs().total_cmp(&(*b - current).abs()))
+        .map_or(0, |(index, _)| index);
+    let index = index.saturating_add_signed(offset).min(SPEEDS.len() - 1);
+    time.set_relative_speed(SPEEDS[index]);
+}
+
+pub fn time_ui_system(
+    mut contexts: EguiContexts,
+    fixed_time: Res<Time<Fixed>>,
+    mut time: ResMut<Time<Physics>>,
+    mut buffer: ResMut<RewindBuffer>,
+    locale: Localizer,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    egui::Window::new(locale.tr("time.title"))
+        .id(egui::Id::new("time"))
+        .anchor(egui::Align2::LEFT_BOTTOM, (10.0, -10.0))
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                let label = if time.is_paused() {
+                    "time.play"
+                } else {
+                    "time.pause"
+                };
+                if ui.button(locale.tr(label)).clicked() {
+                    toggle_pause(&mut time, &mut buffer);
+                }
+                if ui
+                    .add_enabled(time.is_paused(), egui::Button::new(locale.tr("time.step")))
+                    .clicked()
+                {
+                    step(&mut time, &mut buffer, fixed_time.timestep());
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label(locale.tr("time.speed"));
+                let current = time.relative_speed();
+                for &speed in SPEEDS {
+                    if ui
+                        .selectable_label(current == speed, format!("{speed}x"))
+                        .clicked()
+                    {
+                        time.set_relative_speed(speed);
+                    }
+                }
+            });
+
+            if buffer.is_empty() {
+                return;
+            }
+            let last = buffer.len() - 1;
+            let mut tick = buffer.cursor().unwrap_or(last);
+            let seconds_back = format!(
+                "{:.2}",
+                (last - tick) as f32 * fixed_time.timestep().as_secs_f32()
+            );
+            let rewind_label = locale.tr_args("time.rewind", &[("seconds", &seconds_back)]);
+            let slider = egui::Slider::new(&mut tick, 0..=last)
+                .show_value(false)
+                .text(rewind_label);
+            if ui.add(slider).changed() {
+                time.pause();
+                buffer.scrub_to(tick);
+            }
+            ui.label(locale.tr_args(
+                "time.help",
+                &[
+                    ("pause", &format!("{PAUSE_KEY:?}")),
+                    ("step", &format!("{STEP_KEY:?}")),
+                    ("rewind", &format!("{REWIND_KEY:?}")),
+                    ("forward", &format!("{FORWARD_KEY:?}")),
+                    ("slower", &format!("{SLOWER_KEY:?}")),
+                    ("faster", &format!("{FASTER_KEY:?}")),
+                ],
+            ));
+        });
+}
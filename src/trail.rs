@@ -0,0 +1,102 @@
+// Flight trails: a dotted line behind each launched pig from the slingshot to the first
+// thing it hits, left in place until the next launch so the next shot can be adjusted
+// against it. A few earlier trails stay on, fading out with age.
+use std::collections::VecDeque;
+
+use avian2d::prelude::*;
+use bevy::prelude::*;
+
+use crate::{
+    menu::LoadLevel,
+    sim::SETTLE_SPEED,
+    slingshot::{Bird, OnSlingshot},
+};
+
+// Pixels between dots. Smaller and warmer than the white aim preview's, so an old trail
+// isn't mistaken for the shot being lined up.
+const DOT_SPACING: f32 = 25.0;
+const DOT_RADIUS: f32 = 2.5;
+const TRAIL_COLOR: Color = Color::srgb(1.0, 0.75, 0.3);
+// Earlier trails kept besides the current one
+const PAST_TRAILS: usize = 3;
+
+pub struct TrailPlugin;
+
+impl Plugin for TrailPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<LoadLevel>()
+            .init_resource::<Trails>()
+            .add_systems(Update, (record_trail_system, draw_trails_system).chain());
+    }
+}
+
+struct Trail {
+    // The pig being followed, until it hits something, stops or is gone
+    bird: Option<Entity>,
+    dots: Vec<Vec2>,
+}
+
+// Newest first
+#[derive(Resource, Default)]
+pub struct Trails(VecDeque<Trail>);
+
+fn record_trail_system(
+    mut trails: ResMut<Trails>,
+    mut load_level: MessageReader<LoadLevel>,
+    mut launched: RemovedComponents<OnSlingshot>,
+    bird_q: Query<(&Transform, &LinearVelocity, &CollidingEntities), With<Bird>>,
+) {
+    // Trails from another level, or an earlier try, would only mislead
+    if load_level.read().count() > 0 {
+        trails.0.clear();
+    }
+
+    for entity in launched.read() {
+        if bird_q.contains(entity) {
+            // Earlier pigs may still be rolling about, but their flights are over
+            for trail in trails.0.iter_mut() {
+                trail.bird = None;
+            }
+            trails.0.push_front(Trail {
+                bird: Some(entity),
+                dots: Vec::new(),
+            });
+            trails.0.truncate(PAST_TRAILS + 1);
+        }
+    }
+
+    for trail in trails.0.iter_mut() {
+        let Some(bird) = trail.bird else {
+            continue;
+        };
+        let Ok((transform, velocity, colliding)) = bird_q.get(bird) else {
+            trail.bird = None;
+            continue;
+        };
+        let position = transform.translation.truncate();
+        let landed = !colliding.is_empty() || velocity.length() < SETTLE_SPEED;
+        // The point it landed always gets a dot, so the trail ends where the flight did
+        if landed
+            || trail
+                .dots
+                .last()
+                .is_none_or(|&last| last.distance(position) >= DOT_SPACING)
+        {
+            trail.dots.push(position);
+        }
+        if landed {
+            trail.bird = None;
+        }
+    }
+}
+
+// The newest trail is drawn solid and each older one fainter
+fn draw_trails_system(mut gizmos: Gizmos, trails: Res<Trails>) {
+    for (age, trail) in trails.0.iter().enumerate() {
+        let alpha = 1.0 - age as f32 / (PAST_TRAILS + 1) as f32;
+        let color = TRAIL_COLOR.with_alpha(alpha);
+        for &dot in &trail.dots {
+            gizmos.circle_2d(Isometry2d::from_translation(dot), DOT_RADIUS, color);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+// The in-game egui panels: the HUD, and pig and block info. Window titles are
+// translated, so each window gets a fixed id to keep its place when the language changes.
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+use crate::{
+    content::{ContentDiscovered, ContentEntry},
+    dependency::{self, HoveredProject},
+    encyclopedia::{self, EncyclopediaPanel},
+    level::{BlockDescription, CurrentLevel, LEVELS, Pig, PigType, Score},
+    locale::Localizer,
+    menu::{GameState, LoadLevel, MENU_KEY},
+    sandbox::Sandbox,
+    sim::all_settled,
+    slingshot::{Bird, OnSlingshot, SlingshotState},
+};
+
+// Target kinds counted in the HUD, with their string keys
+const PIG_TYPE_NAMES: &[(PigType, &str)] = &[
+    (PigType::King, "pig-type.king"),
+    (PigType::Normal, "pig-type.normal"),
+    (PigType::RedBird, "pig-type.red"),
+    (PigType::BombBird, "pig-type.bomb"),
+    (PigType::TriangleBird, "pig-type.triangle"),
+    (PigType::EggBird, "pig-type.egg"),
+    (PigType::BlueBird, "pig-type.blue"),
+];
+
+// Needs bevy_egui's EguiPlugin to be added by the app
+pub struct UiPlugin;
+
+impl Plugin for UiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            EguiPrimaryContextPass,
+            (
+                (hud_system, pig_info_system, hover_info_system)
+                    .run_if(in_state(GameState::Playing)),
+                touch_ui_system,
+            ),
+        );
+    }
+}
+
+pub fn pig_info_system(
+    mut contexts: EguiContexts,
+    slingshot: Res<SlingshotState>,
+    locale: Localizer,
+) {
+    if let Ok(ctx) = contexts.ctx_mut() {
+        egui::Window::new(locale.tr("pig-info.title"))
+            .id(egui::Id::new("pig_info"))
+            .default_pos((0.0, 200.0))
+            .show(ctx, |ui| {
+                ui.label(locale.tr("pig-info.prompt"));
+                if let Some(disaster) = slingshot.disaster {
+                    if let Some(entry) = locale.content(disaster.content) {
+                        ui.separator();
+                        content_entry_ui(ui, entry, &locale);
+                    }
+                    ui.separator();
+                    ui.label(locale.tr(disaster.effect.description_key()));
+                }
+            });
+    }
+}
+
+// Title and year, the text, and a link to read more if there is one
+pub fn content_entry_ui(ui: &mut egui::Ui, entry: &ContentEntry, locale: &Localizer) {
+    ui.strong(format!("{} ({})", entry.title, entry.year));
+    ui.label(&entry.body);
+    if let Some(link) = &entry.link {
+        ui.hyperlink_to(locale.tr("content.read-more"), link);
+    }
+}
+
+// Once the player touches the screen, make the egui panels easier to hit with a finger
+pub fn touch_ui_system(
+    mut contexts: EguiContexts,
+    touches: Res<Touches>,
+    mut enlarged: Local<bool>,
+) {
+    if *enlarged || !touches.any_just_pressed() {
+        return;
+    }
+    if let Ok(ctx) = contexts.ctx_mut() {
+        ctx.style_mut(|style| {
+            style.spacing.interact_size.y = 40.0;
+            style.spacing.button_padding = egui::vec2(16.0, 10.0);
+            style.spacing.item_spacing = egui::vec2(12.0, 12.0);
+        });
+        *enlarged = true;
+    }
+}
+
+// A strip across the top of the screen that wraps onto more lines when it's narrow:
+// the level, score, pigs left to launch and targets left standing, and buttons for the
+// menu and encyclopedia so touch screens can reach them
+#[allow(clippy::too_many_arguments)]
+pub fn hud_system(
+    mut contexts: EguiContexts,
+    current_level: Res<CurrentLevel>,
+    score: Res<Score>,
+    slingshot: Res<SlingshotState>,
+    sandbox: Res<Sandbox>,
+    loaded_q: Query<(), (With<Bird>, With<OnSlingshot>)>,
+    pig_q: Query<&PigType, With<Pig>>,
+    body_q: Query<(&RigidBody, &LinearVelocity)>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut encyclopedia_panel: ResMut<EncyclopediaPanel>,
+    mut load_level: MessageWriter<LoadLevel>,
+    locale: Localizer,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let level = &LEVELS[current_level.0];
+    let birds_left = loaded_q.iter().count() + slingshot.queue.len();
+    let pigs_left = pig_q.iter().count();
+
+    egui::TopBottomPanel::top("hud").show(ctx, |ui| {
+        ui.horizontal_wrapped(|ui| {
+            ui.strong(locale.tr(level.name_key));
+            ui.separator();
+            ui.label(locale.tr_args("hud.score", &[("score", &score.0)]));
+            ui.separator();
+            let birds: &dyn std::fmt::Display = if sandbox.enabled { &"∞" } else { &birds_left };
+            ui.label(locale.tr_args("hud.birds", &[("count", birds)]));
+            let next = slingshot
+                .queue
+                .front()
+                .and_then(|disaster| locale.content(disaster.content));
+            if let Some(next) = next {
+                ui.label(locale.tr_args("hud.next", &[("disaster", &next.title)]));
+            }
+            ui.separator();
+            ui.label(locale.tr_args("hud.pigs", &[("count", &pigs_left)]));
+            for &(pig_type, name) in PIG_TYPE_NAMES {
+                let count = pig_q.iter().filter(|&&pig| pig == pig_type).count();
+                if count > 0 {
+                    ui.label(format!("{} ×{count}", locale.tr(name)));
+                }
+            }
+            ui.separator();
+            let menu_label = locale.tr_args("hud.menu", &[("key", &format!("{MENU_KEY:?}"))]);
+            if ui.button(menu_label).clicked() {
+                next_state.set(GameState::Paused);
+            }
+            let encyclopedia_label = locale.tr_args(
+                "hud.encyclopedia",
+                &[("key", &format!("{:?}", encyclopedia::TOGGLE_KEY))],
+            );
+            if ui.button(encyclopedia_label).clicked() {
+                encyclopedia_panel.open = !encyclopedia_panel.open;
+            }
+        });
+
+        let outcome = if pigs_left == 0 {
+            Some("hud.cleared")
+        } else if birds_left == 0 && !sandbox.enabled && all_settled(body_q.iter()) {
+            // Not while the last shot is still flying, or knocking things over, since it
+            // may yet clear the level
+            Some("hud.out-of-birds")
+        } else {
+            None
+        };
+        if let Some(outcome) = outcome {
+            ui.horizontal_wrapped(|ui| {
+                ui.strong(locale.tr(outcome));
+                if ui.button(locale.tr("hud.restart")).clicked() {
+                    load_level.write(LoadLevel(current_level.0));
+                }
+            });
+        }
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn hover_info_system(
+    mut contexts: EguiContexts,
+    windows: Query<&Window>,
+    camera_q: Query<(&Camera, &GlobalTransform)>,
+    spatial_query: SpatialQuery,
+    block_desc_q: Query<&BlockDescription>,
+    locale: Localizer,
+    mut discovered: MessageWriter<ContentDiscovered>,
+    mut hovered: ResMut<HoveredProject>,
+    current_level: Res<CurrentLevel>,
+    touches: Res<Touches>,
+    mut last_touch: Local<Option<Vec2>>,
+) {
+    let Some((camera, camera_transform)) = camera_q.iter().next() else {
+        return;
+    };
+    let Some(window) = windows.iter().next() else {
+        return;
+    };
+
+    // Touch screens can't hover, so the last place touched stands in for the cursor
+    if let Some(touch_pos) = touches.first_pressed_position() {
+        *last_touch = Some(touch_pos);
+    }
+
+    hovered.0 = None;
+    if let Some(cursor_pos) = window.cursor_position().or(*last_touch) {
+        if let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_pos) {
+            // Raycast or point projection? Point projection is easier for "hovering".
+            // Let's check for entities at the cursor position.
+            // We'll use a small radius for "picking".
+            let intersections =
+                spatial_query.point_intersections(world_pos, &SpatialQueryFilter::default());
+
+            let desc_ui = egui::Window::new(locale.tr("block-info.title"))
+                .id(egui::Id::new("block_info"))
+                .default_pos((0.0, 100.0));
+
+            // Only show one
+            let desc = intersections
+                .into_iter()
+                .find_map(|entity| block_desc_q.get(entity).ok());
+            if let Some(desc) = desc {
+                discovered.write(ContentDiscovered(desc.0));
+                hovered.0 = Some(desc.0);
+            }
+            let entry = desc.and_then(|desc| locale.content(desc.0).map(|entry| (desc.0, entry)));
+            let level = &LEVELS[current_level.0];
+
+            desc_ui.show(contexts.ctx_mut().unwrap(), |ui| match entry {
+                Some((id, entry)) => {
+                    content_entry_ui(ui, entry, &locale);
+                    let foundations = project_titles(dependency::depends_on(level, id), &locale);
+                    let dependents = project_titles(dependency::needed_by(level, id), &locale);
+                    if !foundations.is_empty() || !dependents.is_empty() {
+                        ui.separator();
+                    }
+                    if !foundations.is_empty() {
+                        ui.label(
+                            locale.tr_args("block-info.depends-on", &[("projects", &foundations)]),
+                        );
+                    }
+                    if !dependents.is_empty() {
+                        ui.label(
+                            locale.tr_args("block-info.needed-by", &[("projects", &dependents)]),
+                        );
+                    }
+                }
+                None => {
+                    ui.label(locale.tr("block-info.prompt"));
+                }
+            });
+        }
+    }
+}
+
+// Comma-separated titles of the given content ids
+fn project_titles(ids: impl Iterator<Item = &'static str>, locale: &Localizer) -> String {
+    ids.filter_map(|id| locale.content(id))
+        .map(|entry| entry.title.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}